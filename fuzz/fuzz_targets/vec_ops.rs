@@ -0,0 +1,92 @@
+//! Drives random sequences of operations against an `AliasableVec`, checking
+//! it always matches a `Vec` oracle.
+
+#![no_main]
+
+use aliasable::prelude::{AliasableVec, UniqueVec};
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Push(u8),
+    Pop,
+    Reserve(u8),
+    Truncate(u8),
+    Clear,
+    WithCapacity(u8),
+    UniquePush(u8),
+    UniquePop,
+    Clone,
+    CloneFrom(AliasableVec<u8>),
+    IntoUnique,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut aliasable = AliasableVec::from_unique(UniqueVec::new());
+    let mut oracle = Vec::new();
+
+    for op in ops {
+        match op {
+            Op::Push(value) => {
+                aliasable.push(value);
+                oracle.push(value);
+            }
+            Op::Pop => assert_eq!(aliasable.pop(), oracle.pop()),
+            Op::Reserve(additional) => {
+                let additional = usize::from(additional);
+                let ptr = aliasable.as_ptr();
+                let fits = aliasable.len() + additional <= aliasable.capacity();
+                aliasable.reserve(additional);
+                assert!(aliasable.capacity() >= aliasable.len() + additional);
+                if fits {
+                    assert_eq!(aliasable.as_ptr(), ptr);
+                }
+            }
+            Op::Truncate(len) => {
+                aliasable.truncate(usize::from(len));
+                oracle.truncate(usize::from(len));
+            }
+            Op::Clear => {
+                let capacity = aliasable.capacity();
+                aliasable.clear();
+                oracle.clear();
+                assert_eq!(aliasable.capacity(), capacity);
+            }
+            Op::WithCapacity(capacity) => {
+                aliasable = AliasableVec::with_capacity(usize::from(capacity));
+                assert!(aliasable.capacity() >= usize::from(capacity));
+                oracle.clear();
+            }
+            Op::UniquePush(value) => {
+                let mut unique = AliasableVec::into_unique(aliasable);
+                unique.push(value);
+                aliasable = AliasableVec::from_unique(unique);
+                oracle.push(value);
+            }
+            Op::UniquePop => {
+                let mut unique = AliasableVec::into_unique(aliasable);
+                assert_eq!(unique.pop(), oracle.pop());
+                aliasable = AliasableVec::from_unique(unique);
+            }
+            Op::Clone => {
+                let clone = aliasable.clone();
+                assert_eq!(&*clone, &*aliasable);
+                aliasable = clone;
+            }
+            Op::CloneFrom(source) => {
+                aliasable.clone_from(&source);
+                oracle.clone_from(&AliasableVec::into_unique(source));
+            }
+            Op::IntoUnique => {
+                let ptr = aliasable.as_ptr();
+                let unique = AliasableVec::into_unique(aliasable);
+                assert_eq!(unique.as_ptr(), ptr);
+                aliasable = AliasableVec::from_unique(unique);
+            }
+        }
+        assert_eq!(&*aliasable, &*oracle);
+        assert_eq!(aliasable.len(), oracle.len());
+        assert!(aliasable.capacity() >= aliasable.len());
+    }
+});
@@ -0,0 +1,188 @@
+//! Typed arena allocator handing out [`AliasableMut`]s.
+
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::{cmp, fmt, slice, str};
+
+use crate::mut_ref::AliasableMut;
+use crate::vec::{AliasableVec, UniqueVec};
+
+/// Capacity of the first chunk of an arena created with [`Arena::new`].
+const INITIAL_CAPACITY: usize = 8;
+
+/// Typed arena allocating values of `T` in chunks of [`AliasableVec`]
+/// storage.
+///
+/// Chunks are never reallocated, so every [`AliasableMut`] handed out stays
+/// valid for as long as the arena is borrowed, and values may hold
+/// `AliasableMut<'arena, T>` links to each other, including cyclic ones. All
+/// values are dropped when the arena is dropped.
+pub struct Arena<T> {
+    chunks: RefCell<Chunks>,
+    // The arena owns the `T`s stored in its chunks.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Arena<T> {
+    /// Constructs a new, empty arena.
+    pub fn new() -> Self {
+        Self::with_capacity(INITIAL_CAPACITY)
+    }
+
+    /// Constructs a new, empty arena which can hold at least `capacity`
+    /// values before allocating a new chunk.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut chunks = Chunks {
+            list: UniqueVec::new(),
+            drop_chunk: drop_chunk::<T>,
+        };
+        chunks.push::<T>(capacity);
+        Self {
+            chunks: RefCell::new(chunks),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of values allocated in the arena.
+    pub fn len(&self) -> usize {
+        self.chunks
+            .borrow()
+            .list
+            .iter()
+            .map(|chunk| chunk.len)
+            .sum()
+    }
+
+    /// Returns `true` if no values have been allocated in the arena.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves `value` into the arena, returning an aliasable handle to it.
+    pub fn alloc(&self, value: T) -> AliasableMut<'_, T> {
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = chunks.reserve::<T>(1);
+        // SAFETY: The chunk has a spare slot which is never handed out again,
+        // and is only freed when the arena is dropped.
+        unsafe {
+            let slot = chunk.ptr.cast::<T>().as_ptr().add(chunk.len);
+            slot.write(value);
+            chunk.len += 1;
+            AliasableMut::from_unique(&mut *slot)
+        }
+    }
+
+    /// Clones the elements of `src` into contiguous slots in the arena,
+    /// returning an aliasable handle to them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T::clone` allocates in the same arena.
+    pub fn alloc_slice(&self, src: &[T]) -> AliasableMut<'_, [T]>
+    where
+        T: Clone,
+    {
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = chunks.reserve::<T>(src.len());
+        // SAFETY: The chunk has `src.len()` spare slots which are never handed
+        // out again, and are only freed when the arena is dropped. The length
+        // of the chunk is bumped after every write so that a panicking clone
+        // never leaves it covering an uninitialized slot.
+        unsafe {
+            let start = chunk.ptr.cast::<T>().as_ptr().add(chunk.len);
+            for (i, value) in src.iter().enumerate() {
+                start.add(i).write(value.clone());
+                chunk.len += 1;
+            }
+            AliasableMut::from_unique(slice::from_raw_parts_mut(start, src.len()))
+        }
+    }
+}
+
+impl Arena<u8> {
+    /// Copies `s` into contiguous slots in the arena, returning an aliasable
+    /// handle to it.
+    pub fn alloc_str(&self, s: &str) -> AliasableMut<'_, str> {
+        let bytes = AliasableMut::into_unique(self.alloc_slice(s.as_bytes()));
+        // SAFETY: The bytes were copied from a `str`.
+        AliasableMut::from_unique(unsafe { str::from_utf8_unchecked_mut(bytes) })
+    }
+}
+
+impl<T> Default for Arena<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Arena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Arena").field("len", &self.len()).finish()
+    }
+}
+
+// SAFETY: The chunks are only ever accessed as `T`s, so the arena can be sent
+// to another thread whenever `T` can.
+unsafe impl<T: Send> Send for Arena<T> {}
+
+/// The chunks of an [`Arena`], with `T` erased.
+///
+/// Keeping this free of `T` means `Arena<T>` has no `Drop` impl of its own,
+/// which would otherwise require everything borrowed by a `T` to strictly
+/// outlive the arena and so rule out values linking to each other.
+struct Chunks {
+    // The chunk being allocated from is always last.
+    list: UniqueVec<RawChunk>,
+    drop_chunk: unsafe fn(RawChunk),
+}
+
+/// The parts of an `AliasableVec<T>`.
+#[derive(Clone, Copy)]
+struct RawChunk {
+    ptr: NonNull<u8>,
+    len: usize,
+    cap: usize,
+}
+
+impl Chunks {
+    fn push<T>(&mut self, capacity: usize) {
+        let vec = AliasableVec::from_unique(UniqueVec::<T>::with_capacity(capacity));
+        let (ptr, len, cap) = vec.into_raw_parts();
+        self.list.push(RawChunk {
+            ptr: ptr.cast(),
+            len,
+            cap,
+        });
+    }
+
+    /// Returns a chunk with at least `additional` spare slots, pushing a new
+    /// one if the current chunk is too full.
+    fn reserve<T>(&mut self, additional: usize) -> &mut RawChunk {
+        let current = self.list.last().expect("arena has no chunks");
+        if current.cap - current.len < additional {
+            let doubled = current.cap.checked_mul(2).expect("capacity overflow");
+            self.push::<T>(cmp::max(additional, doubled));
+        }
+        self.list.last_mut().expect("arena has no chunks")
+    }
+}
+
+impl Drop for Chunks {
+    fn drop(&mut self) {
+        for chunk in self.list.drain(..) {
+            // SAFETY: Every chunk was pushed with the same `T` that
+            // `drop_chunk` was instantiated with.
+            unsafe { (self.drop_chunk)(chunk) };
+        }
+    }
+}
+
+unsafe fn drop_chunk<T>(chunk: RawChunk) {
+    drop(AliasableVec::<T>::from_raw_parts(
+        chunk.ptr.cast(),
+        chunk.len,
+        chunk.cap,
+    ));
+}
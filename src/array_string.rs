@@ -0,0 +1,245 @@
+//! Aliasable fixed-capacity string which needs no allocator.
+
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::{fmt, str};
+
+use crate::array_vec::{AliasableArrayVec, CapacityError};
+
+/// Aliasable (non `core::ptr::Unique`) string holding up to `N` bytes of
+/// UTF-8 in place, for use without an allocator.
+///
+/// The bytes live inside the `AliasableArrayString` itself, so their
+/// addresses change whenever it is moved. Like [`AliasableArrayVec`], the type
+/// is deliberately `!Unpin`, so while it stays in place, pointers to the bytes
+/// stay valid across calls to `&mut self` methods such as `push_str`, subject
+/// to the same caveat about the current semantics of `!Unpin`.
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AliasableArrayString<const N: usize>(AliasableArrayVec<u8, N>);
+
+impl<const N: usize> AliasableArrayString<N> {
+    /// Constructs a new, empty `AliasableArrayString`.
+    pub const fn new() -> Self {
+        Self(AliasableArrayVec::new())
+    }
+
+    /// Returns the length of the string in bytes.
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of bytes the string can hold, which is always `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a raw pointer to the first byte.
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    /// Returns the string as a `str`.
+    pub fn as_str(&self) -> &str {
+        self
+    }
+
+    /// Appends a string slice, or does nothing if it doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string has less than `s.len()` bytes of spare capacity.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        // Appending a `str` keeps the contents valid UTF-8.
+        self.0.try_extend_from_slice(s.as_bytes())
+    }
+
+    /// Appends a string slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string doesn't have enough spare capacity.
+    pub fn push_str(&mut self, s: &str) {
+        assert!(self.try_push_str(s).is_ok(), "AliasableArrayString is full");
+    }
+
+    /// Appends a character, or does nothing if it doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the string doesn't have enough spare capacity for the UTF-8
+    /// encoding of `c`.
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends a character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string doesn't have enough spare capacity.
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Removes the last character of the string and returns it, or `None` if
+    /// it is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+        self.0.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a character boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.is_char_boundary(len), "not a char boundary");
+            self.0.truncate(len);
+        }
+    }
+
+    /// Empties the string.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<const N: usize> Deref for AliasableArrayString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        // SAFETY: `AliasableArrayString` will only ever contain UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl<const N: usize> DerefMut for AliasableArrayString<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        // SAFETY: `AliasableArrayString` will only ever contain UTF-8.
+        unsafe { str::from_utf8_unchecked_mut(&mut self.0) }
+    }
+}
+
+impl<const N: usize> AsRef<str> for AliasableArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> AsMut<str> for AliasableArrayString<N> {
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<const N: usize> Borrow<str> for AliasableArrayString<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> BorrowMut<str> for AliasableArrayString<N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<const N: usize> fmt::Write for AliasableArrayString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> fmt::Debug for AliasableArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for AliasableArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq<str> for AliasableArrayString<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for AliasableArrayString<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd<str> for AliasableArrayString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+// Deriving `Hash` would be incorrect because it would hash as bytes and not a string.
+#[allow(clippy::derived_hash_with_manual_eq)]
+impl<const N: usize> Hash for AliasableArrayString<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        (**self).hash(hasher);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> From<heapless::String<N>> for AliasableArrayString<N> {
+    fn from(s: heapless::String<N>) -> Self {
+        let mut aliasable = Self::new();
+        aliasable.push_str(&s);
+        aliasable
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> From<AliasableArrayString<N>> for heapless::String<N> {
+    fn from(aliasable: AliasableArrayString<N>) -> Self {
+        let mut s = heapless::String::new();
+        // Both have a capacity of `N`, so everything fits.
+        let _ = s.push_str(&aliasable);
+        s
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> From<arrayvec::ArrayString<N>> for AliasableArrayString<N> {
+    fn from(s: arrayvec::ArrayString<N>) -> Self {
+        let mut aliasable = Self::new();
+        aliasable.push_str(&s);
+        aliasable
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> From<AliasableArrayString<N>> for arrayvec::ArrayString<N> {
+    fn from(aliasable: AliasableArrayString<N>) -> Self {
+        let mut s = arrayvec::ArrayString::new();
+        s.push_str(&aliasable);
+        s
+    }
+}
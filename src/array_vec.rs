@@ -0,0 +1,355 @@
+//! Aliasable fixed-capacity vector which needs no allocator.
+
+use core::borrow::{Borrow, BorrowMut};
+use core::cell::UnsafeCell;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomPinned;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::{fmt, ptr, slice};
+
+/// The error returned when an operation would exceed the capacity of a
+/// fixed-capacity container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// Aliasable (non `core::ptr::Unique`) vector holding up to `N` elements in
+/// place, for use without an allocator.
+///
+/// The elements live inside the `AliasableArrayVec` itself, so their
+/// addresses change whenever it is moved.
+///
+/// The type is deliberately `!Unpin`, and its storage is an `UnsafeCell`. The
+/// compiler and Miri currently don't treat an `&mut` to a `!Unpin` type as
+/// asserting unique access, so while the vec stays in place, pointers to the
+/// elements stay valid across calls to `&mut self` methods such as `push` and
+/// `pop`. This relies on the current semantics of `!Unpin` rather than on a
+/// language guarantee, and may need revisiting if those semantics change.
+pub struct AliasableArrayVec<T, const N: usize> {
+    len: usize,
+    buf: UnsafeCell<MaybeUninit<[T; N]>>,
+    // `&mut` to a `!Unpin` type currently doesn't assert unique access.
+    _pinned: PhantomPinned,
+}
+
+impl<T, const N: usize> AliasableArrayVec<T, N> {
+    /// Constructs a new, empty `AliasableArrayVec`.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            buf: UnsafeCell::new(MaybeUninit::uninit()),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// Returns the number of elements in the vec.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the vec can hold, which is always `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the vec contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the vec holds `N` elements.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns a raw pointer to the first element.
+    pub const fn as_ptr(&self) -> *const T {
+        self.buf.get().cast()
+    }
+
+    /// Returns a raw mutable pointer to the first element.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.get().cast()
+    }
+
+    /// Appends an element to the back of the vec, or hands it back if the vec
+    /// is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns the element if the vec is full.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        // SAFETY: There is spare capacity, so the slot after the last element
+        // is uninitialized.
+        unsafe { self.as_mut_ptr().add(self.len).write(value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends an element to the back of the vec.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vec is full.
+    pub fn push(&mut self, value: T) {
+        assert!(self.try_push(value).is_ok(), "AliasableArrayVec is full");
+    }
+
+    /// Removes the last element of the vec and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The slot was initialized and is no longer tracked as such.
+        Some(unsafe { self.as_mut_ptr().add(self.len).read() })
+    }
+
+    /// Shortens the vec, keeping the first `len` elements and dropping the
+    /// rest.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len` is within the initialized elements.
+            unsafe { self.as_mut_ptr().add(len) },
+            self.len - len,
+        );
+        // Update the length first, so a panicking drop never leaves it covering
+        // a dropped element.
+        self.len = len;
+        // SAFETY: The elements were initialized and are no longer tracked.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Drops all the elements of the vec.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Clones and appends all the elements of a slice, or does nothing if
+    /// they don't all fit.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the vec has less than `other.len()` spare capacity.
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        if N - self.len < other.len() {
+            return Err(CapacityError);
+        }
+        for value in other {
+            // SAFETY: The capacity was checked up front, and the length is
+            // kept up to date in case `clone` panics.
+            unsafe { self.as_mut_ptr().add(self.len).write(value.clone()) };
+            self.len += 1;
+        }
+        Ok(())
+    }
+}
+
+// SAFETY: The storage is only mutated through `&mut self`.
+unsafe impl<T: Sync, const N: usize> Sync for AliasableArrayVec<T, N> {}
+
+impl<T, const N: usize> Drop for AliasableArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Deref for AliasableArrayVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+}
+
+impl<T, const N: usize> DerefMut for AliasableArrayVec<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for AliasableArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for AliasableArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for AliasableArrayVec<T, N> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for AliasableArrayVec<T, N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Extend<T> for AliasableArrayVec<T, N> {
+    /// Appends the elements of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vec fills up.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for AliasableArrayVec<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T, const N: usize> Default for AliasableArrayVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for AliasableArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        // The clone has the same capacity as `self`, so everything fits.
+        let _ = clone.try_extend_from_slice(self);
+        clone
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<AliasableArrayVec<U, M>>
+    for AliasableArrayVec<T, N>
+{
+    #[inline]
+    fn eq(&self, other: &AliasableArrayVec<U, M>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for AliasableArrayVec<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]>
+    for AliasableArrayVec<T, N>
+{
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        **self == other[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for AliasableArrayVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for AliasableArrayVec<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for AliasableArrayVec<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for AliasableArrayVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> From<heapless::Vec<T, N>> for AliasableArrayVec<T, N> {
+    fn from(vec: heapless::Vec<T, N>) -> Self {
+        let mut aliasable = Self::new();
+        aliasable.extend(vec);
+        aliasable
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> From<AliasableArrayVec<T, N>> for heapless::Vec<T, N> {
+    fn from(mut aliasable: AliasableArrayVec<T, N>) -> Self {
+        let mut vec = heapless::Vec::new();
+        let len = aliasable.len;
+        // Forget the elements first, so they are moved out exactly once.
+        aliasable.len = 0;
+        for i in 0..len {
+            // SAFETY: The elements were initialized and are no longer tracked.
+            let value = unsafe { aliasable.as_ptr().add(i).read() };
+            // Both have a capacity of `N`, so everything fits.
+            let _ = vec.push(value);
+        }
+        vec
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> From<arrayvec::ArrayVec<T, N>> for AliasableArrayVec<T, N> {
+    fn from(vec: arrayvec::ArrayVec<T, N>) -> Self {
+        let mut aliasable = Self::new();
+        aliasable.extend(vec);
+        aliasable
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> From<AliasableArrayVec<T, N>> for arrayvec::ArrayVec<T, N> {
+    fn from(mut aliasable: AliasableArrayVec<T, N>) -> Self {
+        let mut vec = arrayvec::ArrayVec::new();
+        let len = aliasable.len;
+        // Forget the elements first, so they are moved out exactly once.
+        aliasable.len = 0;
+        for i in 0..len {
+            // SAFETY: The elements were initialized and are no longer tracked.
+            vec.push(unsafe { aliasable.as_ptr().add(i).read() });
+        }
+        vec
+    }
+}
@@ -1,6 +1,8 @@
 //! Aliasable `Box`.
 
+use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::mem::ManuallyDrop;
@@ -8,6 +10,11 @@ use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use core::ptr::NonNull;
 
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::vec::AliasableVec;
+
 pub use alloc::boxed::Box as UniqueBox;
 
 /// Basic aliasable (non `core::ptr::Unique`) alternative to
@@ -55,6 +62,39 @@ impl<T: ?Sized> AliasableBox<T> {
         }
     }
 
+    /// Consumes the box, returning a pointer to its allocation.
+    #[inline]
+    pub(crate) fn into_raw(aliasable: Self) -> NonNull<T> {
+        // Ensure we don't drop `aliasable` as the caller takes over the
+        // allocation.
+        ManuallyDrop::new(aliasable).0
+    }
+
+    /// Returns the pointer to the allocation, without asserting any access to
+    /// it.
+    #[inline]
+    pub(crate) fn as_raw(aliasable: &Self) -> NonNull<T> {
+        aliasable.0
+    }
+
+    /// Reconstructs a box from a pointer returned by [`AliasableBox::into_raw`].
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: NonNull<T>) -> Self {
+        Self(ptr)
+    }
+
+    /// Replaces the value through the raw pointer, so that other raw pointers
+    /// to it stay valid, and returns the previous value.
+    #[inline]
+    pub(crate) fn replace(aliasable: &mut Self, value: T) -> T
+    where
+        T: Sized,
+    {
+        // SAFETY: We own the allocation, and the `&mut` guarantees no
+        // references to the value are live.
+        unsafe { aliasable.0.as_ptr().replace(value) }
+    }
+
     #[inline]
     unsafe fn reclaim_as_unique_box(&mut self) -> UniqueBox<T> {
         UniqueBox::from_raw(self.0.as_ptr())
@@ -67,6 +107,92 @@ impl<T: ?Sized> From<UniqueBox<T>> for AliasableBox<T> {
     }
 }
 
+impl<T, const N: usize> From<AliasableBox<[T; N]>> for AliasableBox<[T]> {
+    fn from(aliasable: AliasableBox<[T; N]>) -> Self {
+        // Ensure we don't drop `aliasable` as we are transferring the
+        // allocation and we don't want a use after free.
+        let aliasable = ManuallyDrop::new(aliasable);
+        // The pointer is unchanged, it just gains the length of the array.
+        AliasableBox(aliasable.0)
+    }
+}
+
+impl<T> From<T> for AliasableBox<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::from_unique(UniqueBox::new(value))
+    }
+}
+
+impl<T: Clone> From<&[T]> for AliasableBox<[T]> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_unique(UniqueBox::from(slice))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for AliasableBox<[T]> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self::from_unique(UniqueBox::from(array))
+    }
+}
+
+impl From<&str> for AliasableBox<str> {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self::from_unique(UniqueBox::from(s))
+    }
+}
+
+impl<T> From<AliasableVec<T>> for AliasableBox<[T]> {
+    /// Converts the vec into a boxed slice, which reallocates if the vec has
+    /// excess capacity.
+    #[inline]
+    fn from(vec: AliasableVec<T>) -> Self {
+        Self::from_unique(AliasableVec::into_unique(vec).into_boxed_slice())
+    }
+}
+
+impl<T> From<AliasableBox<[T]>> for AliasableVec<T> {
+    #[inline]
+    fn from(aliasable: AliasableBox<[T]>) -> Self {
+        AliasableVec::from_unique(AliasableBox::into_unique(aliasable).into_vec())
+    }
+}
+
+impl<T, const N: usize> TryFrom<AliasableVec<T>> for AliasableBox<[T; N]> {
+    type Error = AliasableVec<T>;
+
+    /// Converts the vec into a boxed array if its length is exactly `N`,
+    /// otherwise handing it back unchanged.
+    #[inline]
+    fn try_from(vec: AliasableVec<T>) -> Result<Self, Self::Error> {
+        UniqueBox::try_from(AliasableVec::into_unique(vec))
+            .map(Self::from_unique)
+            .map_err(AliasableVec::from_unique)
+    }
+}
+
+impl<T, const N: usize> TryFrom<AliasableBox<[T]>> for AliasableBox<[T; N]> {
+    type Error = AliasableBox<[T]>;
+
+    /// Converts the boxed slice into a boxed array if its length is exactly
+    /// `N`, otherwise handing it back unchanged.
+    #[inline]
+    fn try_from(aliasable: AliasableBox<[T]>) -> Result<Self, Self::Error> {
+        if aliasable.len() == N {
+            // Ensure we don't drop `aliasable` as we are transferring the
+            // allocation and we don't want a use after free.
+            let aliasable = ManuallyDrop::new(aliasable);
+            // The pointer is unchanged, it just loses the length of the slice.
+            Ok(AliasableBox(aliasable.0.cast()))
+        } else {
+            Err(aliasable)
+        }
+    }
+}
+
 impl<T: ?Sized> Drop for AliasableBox<T> {
     fn drop(&mut self) {
         // SAFETY: As `self` is being dropped we can safely assume any aliasing
@@ -97,7 +223,7 @@ impl<T: ?Sized> DerefMut for AliasableBox<T> {
 impl<T: ?Sized> AsRef<T> for AliasableBox<T> {
     #[inline]
     fn as_ref(&self) -> &T {
-        &*self
+        self
     }
 }
 
@@ -107,6 +233,20 @@ impl<T: ?Sized> AsMut<T> for AliasableBox<T> {
     }
 }
 
+impl<T: ?Sized> Borrow<T> for AliasableBox<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for AliasableBox<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
 impl<T: ?Sized> fmt::Debug for AliasableBox<T>
 where
     T: fmt::Debug,
@@ -116,6 +256,21 @@ where
     }
 }
 
+impl<T: ?Sized> fmt::Display for AliasableBox<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), f)
+    }
+}
+
+impl<T: ?Sized> fmt::Pointer for AliasableBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.0, f)
+    }
+}
+
 unsafe impl<T: ?Sized> Send for AliasableBox<T> where T: Send {}
 unsafe impl<T: ?Sized> Sync for AliasableBox<T> where T: Sync {}
 
@@ -169,6 +324,33 @@ impl<T: PartialOrd + ?Sized> PartialOrd for AliasableBox<T> {
     }
 }
 
+impl<T: PartialEq + ?Sized> PartialEq<UniqueBox<T>> for AliasableBox<T> {
+    #[inline]
+    fn eq(&self, other: &UniqueBox<T>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialOrd + ?Sized> PartialOrd<UniqueBox<T>> for AliasableBox<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &UniqueBox<T>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl_slice_eq!([] AliasableBox<[T]>, [U]);
+impl_slice_eq!(['a,] AliasableBox<[T]>, &'a [U]);
+impl_slice_eq!([const N: usize,] AliasableBox<[T]>, [U; N]);
+impl_slice_eq!([] AliasableBox<[T]>, Vec<U>);
+impl_slice_ord!([] AliasableBox<[T]>, [T]);
+impl_slice_ord!(['a,] AliasableBox<[T]>, &'a [T]);
+impl_slice_ord!([const N: usize,] AliasableBox<[T]>, [T; N]);
+impl_slice_ord!([] AliasableBox<[T]>, Vec<T>);
+
+impl_str_cmp!([] AliasableBox<str>, str);
+impl_str_cmp!(['a] AliasableBox<str>, &'a str);
+impl_str_cmp!([] AliasableBox<str>, String);
+
 impl<T: Ord + ?Sized> Ord for AliasableBox<T> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -182,6 +364,16 @@ impl<T: Hash + ?Sized> Hash for AliasableBox<T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: ?Sized> std::error::Error for AliasableBox<T>
+where
+    T: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        (**self).source()
+    }
+}
+
 #[cfg(feature = "stable_deref_trait")]
 unsafe impl<T: ?Sized> crate::StableDeref for AliasableBox<T> {}
 
@@ -207,3 +399,190 @@ unsafe impl<T, U: ?Sized> unsize::CoerciblePtr<U> for AliasableBox<T> {
         AliasableBox(ptr)
     }
 }
+
+#[cfg(aliasable_nightly)]
+impl<T, U> core::ops::CoerceUnsized<AliasableBox<U>> for AliasableBox<T>
+where
+    T: ?Sized + core::marker::Unsize<U>,
+    U: ?Sized,
+{
+}
+
+#[cfg(aliasable_nightly)]
+impl<T, U> core::ops::DispatchFromDyn<AliasableBox<U>> for AliasableBox<T>
+where
+    T: ?Sized + core::marker::Unsize<U>,
+    U: ?Sized,
+{
+}
+
+// SAFETY: The pointee of an `AliasableBox` never moves while the box is alive.
+#[cfg(aliasable_nightly)]
+unsafe impl<T: ?Sized> core::pin::PinCoerceUnsized for AliasableBox<T> {}
+
+unsafe impl crate::io_buf::IoBuf for AliasableBox<[u8]> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr().cast()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+unsafe impl crate::io_buf::IoBufMut for AliasableBox<[u8]> {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_ptr().cast()
+    }
+
+    /// The bytes of a boxed slice are always initialized, so this does
+    /// nothing.
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.0.len());
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::Zeroize> zeroize::Zeroize for AliasableBox<T> {
+    fn zeroize(&mut self) {
+        (**self).zeroize();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for AliasableBox<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary(u).map(Self::from_unique)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary_take_rest(u).map(Self::from_unique)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UniqueBox::<T>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for AliasableBox<[T]> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary(u).map(Self::from_unique)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary_take_rest(u).map(Self::from_unique)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UniqueBox::<[T]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AliasableBox<str> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary(u).map(Self::from_unique)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueBox::arbitrary_take_rest(u).map(Self::from_unique)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UniqueBox::<str>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary for AliasableBox<T> {
+    type Parameters = <UniqueBox<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = proptest::strategy::Map<
+        proptest::arbitrary::StrategyFor<UniqueBox<T>>,
+        fn(UniqueBox<T>) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::arbitrary::any_with::<UniqueBox<T>>(args),
+            Self::from_unique,
+        )
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: ?Sized + borsh::BorshSerialize> borsh::BorshSerialize for AliasableBox<T> {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        (**self).serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: ?Sized> borsh::BorshDeserialize for AliasableBox<T>
+where
+    UniqueBox<T>: borsh::BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        UniqueBox::deserialize_reader(reader).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: ?Sized + bincode::Encode> bincode::Encode for AliasableBox<T> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        (**self).encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context, T: ?Sized> bincode::Decode<Context> for AliasableBox<T>
+where
+    UniqueBox<T>: bincode::Decode<Context>,
+{
+    #[inline]
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueBox::decode(decoder).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, T: ?Sized> bincode::BorrowDecode<'de, Context> for AliasableBox<T>
+where
+    UniqueBox<T>: bincode::BorrowDecode<'de, Context>,
+{
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueBox::borrow_decode(decoder).map(Self::from_unique)
+    }
+}
+
+// `postcard` has no maximum size for `Vec` or `String`, which are unbounded,
+// so only boxes get one.
+#[cfg(feature = "postcard")]
+impl<T: postcard::experimental::max_size::MaxSize> postcard::experimental::max_size::MaxSize
+    for AliasableBox<T>
+{
+    const POSTCARD_MAX_SIZE: usize = T::POSTCARD_MAX_SIZE;
+}
@@ -0,0 +1,388 @@
+//! Aliasable `BTreeMap`.
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::Index;
+
+use alloc::collections::btree_map;
+
+use crate::boxed::{AliasableBox, UniqueBox};
+
+pub use alloc::collections::BTreeMap as UniqueBTreeMap;
+
+/// Ordered map whose values are each stored in their own [`AliasableBox`].
+///
+/// Unlike [`alloc::collections::BTreeMap`], values are never moved by the map
+/// itself, so references and raw pointers to a value stay valid across
+/// insertions and removals of other entries. Raw pointers from
+/// [`AliasableBTreeMap::get_ptr`] also stay valid across replacing the value
+/// with [`AliasableBTreeMap::insert`], which writes through the same pointer.
+pub struct AliasableBTreeMap<K, V>(UniqueBTreeMap<K, AliasableBox<V>>);
+
+impl<K, V> AliasableBTreeMap<K, V> {
+    /// Makes a new, empty `AliasableBTreeMap`.
+    pub fn new() -> Self {
+        Self(UniqueBTreeMap::new())
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Clears the map, removing all elements.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by key.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.0.iter())
+    }
+
+    /// Gets a mutable iterator over the entries of the map, sorted by key.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.0.iter_mut())
+    }
+}
+
+impl<K: Ord, V> AliasableBTreeMap<K, V> {
+    /// Construct an `AliasableBTreeMap` from a [`UniqueBTreeMap`].
+    ///
+    /// This moves every value into its own allocation.
+    pub fn from_unique(unique: UniqueBTreeMap<K, V>) -> Self {
+        Self(
+            unique
+                .into_iter()
+                .map(|(k, v)| (k, AliasableBox::from_unique(UniqueBox::new(v))))
+                .collect(),
+        )
+    }
+
+    /// Consumes the [`AliasableBTreeMap`] and converts it back into a
+    /// non-aliasable [`UniqueBTreeMap`].
+    ///
+    /// This moves every value out of its own allocation.
+    pub fn into_unique(aliasable: AliasableBTreeMap<K, V>) -> UniqueBTreeMap<K, V> {
+        aliasable
+            .0
+            .into_iter()
+            .map(|(k, v)| (k, *AliasableBox::into_unique(v)))
+            .collect()
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get(key).map(|v| &**v)
+    }
+
+    /// Returns a raw pointer to the value corresponding to the key.
+    ///
+    /// The pointer stays valid until the entry is removed, including across
+    /// replacing the value with [`AliasableBTreeMap::insert`].
+    pub fn get_ptr<Q>(&self, key: &Q) -> Option<*const V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0
+            .get(key)
+            .map(|v| AliasableBox::as_raw(v).as_ptr().cast_const())
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.get_mut(key).map(|v| &mut **v)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.contains_key(key)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already had a value for the key, the value is replaced in
+    /// place, keeping its address, and the old value is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                let _ = entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.0.remove(key).map(|v| *AliasableBox::into_unique(v))
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.0.entry(key) {
+            btree_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry(entry)),
+            btree_map::Entry::Vacant(entry) => Entry::Vacant(VacantEntry(entry)),
+        }
+    }
+}
+
+impl<K: Ord, V> From<UniqueBTreeMap<K, V>> for AliasableBTreeMap<K, V> {
+    #[inline]
+    fn from(unique: UniqueBTreeMap<K, V>) -> Self {
+        Self::from_unique(unique)
+    }
+}
+
+impl<K: Ord, V> From<AliasableBTreeMap<K, V>> for UniqueBTreeMap<K, V> {
+    #[inline]
+    fn from(aliasable: AliasableBTreeMap<K, V>) -> Self {
+        AliasableBTreeMap::into_unique(aliasable)
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AliasableBTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for AliasableBTreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            let _ = self.insert(k, v);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a AliasableBTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut AliasableBTreeMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, Q, V> Index<&Q> for AliasableBTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for AliasableBTreeMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Default for AliasableBTreeMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for AliasableBTreeMap<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for AliasableBTreeMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for AliasableBTreeMap<K, V> {}
+
+/// An iterator over the entries of an [`AliasableBTreeMap`].
+pub struct Iter<'a, K, V>(btree_map::Iter<'a, K, AliasableBox<V>>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, &**v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A mutable iterator over the entries of an [`AliasableBTreeMap`].
+pub struct IterMut<'a, K, V>(btree_map::IterMut<'a, K, AliasableBox<V>>);
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, &mut **v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A view into a single entry in an [`AliasableBTreeMap`], which may either
+/// be vacant or occupied.
+pub enum Entry<'a, K, V> {
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+impl<'a, K: Ord, V> Entry<'a, K, V> {
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Ord, V: Default> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// A view into a vacant entry in an [`AliasableBTreeMap`].
+pub struct VacantEntry<'a, K, V>(btree_map::VacantEntry<'a, K, AliasableBox<V>>);
+
+impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the `VacantEntry`.
+    pub fn key(&self) -> &K {
+        self.0.key()
+    }
+
+    /// Take ownership of the key.
+    pub fn into_key(self) -> K {
+        self.0.into_key()
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, and returns a
+    /// mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.0
+            .insert(AliasableBox::from_unique(UniqueBox::new(value)))
+    }
+}
+
+/// A view into an occupied entry in an [`AliasableBTreeMap`].
+pub struct OccupiedEntry<'a, K, V>(btree_map::OccupiedEntry<'a, K, AliasableBox<V>>);
+
+impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        self.0.key()
+    }
+
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self) -> &V {
+        self.0.get()
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.0.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to its value.
+    pub fn into_mut(self) -> &'a mut V {
+        self.0.into_mut()
+    }
+
+    /// Sets the value of the entry in place, keeping its address, and returns
+    /// the entry's old value.
+    pub fn insert(&mut self, value: V) -> V {
+        AliasableBox::replace(self.0.get_mut(), value)
+    }
+
+    /// Takes the value of the entry out of the map, and returns it.
+    pub fn remove(self) -> V {
+        *AliasableBox::into_unique(self.0.remove())
+    }
+}
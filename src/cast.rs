@@ -0,0 +1,106 @@
+//! Casting between plain data types with [`bytemuck`], reusing the storage of
+//! aliasable containers.
+//!
+//! Every fallible cast checks the alignment and size of the types involved,
+//! and hands back the original container along with the error on mismatch.
+//! Casting never changes the alignment an allocation is freed with, so casts
+//! between types of different alignments, such as `u8` and `u32`, fail with
+//! [`PodCastError::AlignmentMismatch`].
+
+use bytemuck::{AnyBitPattern, NoUninit, PodCastError};
+
+use crate::mut_ref::AliasableMut;
+
+#[cfg(feature = "alloc")]
+use crate::boxed::AliasableBox;
+#[cfg(feature = "alloc")]
+use crate::vec::AliasableVec;
+
+/// The result of a fallible cast, holding the cast container on success or
+/// the error along with the original container on failure.
+pub type CastResult<T, U> = Result<T, (PodCastError, U)>;
+
+/// Attempts to cast the content type of an [`AliasableVec`], reusing its
+/// allocation.
+///
+/// # Errors
+///
+/// Fails if the types have different alignments, or if the length or
+/// capacity of the vec in bytes is not a whole number of `B`s.
+#[cfg(feature = "alloc")]
+pub fn try_cast_vec<A: NoUninit, B: AnyBitPattern>(
+    aliasable: AliasableVec<A>,
+) -> CastResult<AliasableVec<B>, AliasableVec<A>> {
+    bytemuck::allocation::try_cast_vec(AliasableVec::into_unique(aliasable))
+        .map(AliasableVec::from_unique)
+        .map_err(|(err, unique)| (err, AliasableVec::from_unique(unique)))
+}
+
+/// Attempts to cast the content type of an [`AliasableBox`], reusing its
+/// allocation.
+///
+/// # Errors
+///
+/// Fails if the types have different alignments or sizes.
+#[cfg(feature = "alloc")]
+pub fn try_cast_box<A: NoUninit, B: AnyBitPattern>(
+    aliasable: AliasableBox<A>,
+) -> CastResult<AliasableBox<B>, AliasableBox<A>> {
+    bytemuck::allocation::try_cast_box(AliasableBox::into_unique(aliasable))
+        .map(AliasableBox::from_unique)
+        .map_err(|(err, unique)| (err, AliasableBox::from_unique(unique)))
+}
+
+/// Attempts to cast the content type of a boxed slice, reusing its
+/// allocation.
+///
+/// # Errors
+///
+/// Fails if the types have different alignments, or if the length of the
+/// slice in bytes is not a whole number of `B`s.
+#[cfg(feature = "alloc")]
+pub fn try_cast_slice_box<A: NoUninit, B: AnyBitPattern>(
+    aliasable: AliasableBox<[A]>,
+) -> CastResult<AliasableBox<[B]>, AliasableBox<[A]>> {
+    bytemuck::allocation::try_cast_slice_box(AliasableBox::into_unique(aliasable))
+        .map(AliasableBox::from_unique)
+        .map_err(|(err, unique)| (err, AliasableBox::from_unique(unique)))
+}
+
+/// Attempts to cast the pointee type of an [`AliasableMut`].
+///
+/// # Errors
+///
+/// Fails if the types have different sizes, or if the pointer is not aligned
+/// for `B`.
+pub fn try_cast_mut<A, B>(
+    aliasable: AliasableMut<'_, A>,
+) -> CastResult<AliasableMut<'_, B>, AliasableMut<'_, A>>
+where
+    A: NoUninit + AnyBitPattern,
+    B: NoUninit + AnyBitPattern,
+{
+    // Check the cast through a shared reference first, so that the original
+    // handle can be returned on failure.
+    if let Err(err) = bytemuck::try_cast_ref::<A, B>(&aliasable) {
+        return Err((err, aliasable));
+    }
+    let unique = AliasableMut::into_unique(aliasable);
+    Ok(AliasableMut::from_unique(bytemuck::cast_mut(unique)))
+}
+
+/// Casts the pointee type of an [`AliasableMut`].
+///
+/// # Panics
+///
+/// Panics if [`try_cast_mut`] would fail.
+pub fn cast_mut<A, B>(aliasable: AliasableMut<'_, A>) -> AliasableMut<'_, B>
+where
+    A: NoUninit + AnyBitPattern,
+    B: NoUninit + AnyBitPattern,
+{
+    match try_cast_mut(aliasable) {
+        Ok(cast) => cast,
+        Err((err, _)) => panic!("cast_mut: {:?}", err),
+    }
+}
@@ -0,0 +1,150 @@
+//! Aliasable `Cell`-like handle for mutating through shared copies.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// A copyable handle to a mutable location, which can read and write it like
+/// a [`core::cell::Cell`] without owning it.
+///
+/// All accesses are raw pointer reads and writes of the whole value, and no
+/// reference to the location is ever handed out, so any number of copies of
+/// the handle can be used to mutate the location in turn. Like a `Cell`, the
+/// handle can't be sent or shared across threads.
+///
+/// Handles are created from an `&mut` or with [`AliasableMut::as_cell`].
+///
+/// [`AliasableMut::as_cell`]: crate::prelude::AliasableMut::as_cell
+#[repr(transparent)]
+pub struct AliasableCell<'a, T> {
+    inner: NonNull<T>,
+    // We use `T` here to ensure `T` is invariant.
+    _lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> AliasableCell<'a, T> {
+    /// Construct an `AliasableCell` from an `&mut`, which stays borrowed for as
+    /// long as any copy of the handle is in use.
+    #[inline]
+    pub const fn from_unique(ptr: &'a mut T) -> Self {
+        Self {
+            // SAFETY: References are never null.
+            inner: unsafe { NonNull::new_unchecked(ptr) },
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// Construct an `AliasableCell` from a raw pointer without reborrowing it.
+    ///
+    /// # Safety
+    ///
+    /// `inner` must be valid for reads and writes for `'a`, and only be
+    /// accessed through raw pointers for as long as any copy of the handle is
+    /// in use.
+    #[inline]
+    pub(crate) const unsafe fn from_raw(inner: NonNull<T>) -> Self {
+        Self {
+            inner,
+            _lifetime: PhantomData,
+        }
+    }
+
+    /// Returns a raw pointer to the location.
+    #[inline]
+    pub const fn as_ptr(self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    /// Sets the value, dropping the previous one.
+    #[inline]
+    pub fn set(self, value: T) {
+        // The previous value is dropped once the new one is in place, in case
+        // its destructor accesses the location through another copy.
+        drop(self.replace(value));
+    }
+
+    /// Sets the value and returns the previous one.
+    #[inline]
+    pub fn replace(self, value: T) -> T {
+        // SAFETY: The location is borrowed mutably for `'a`, is only accessed
+        // through copies of this handle which can't be used across threads,
+        // and no references to it are handed out.
+        unsafe { self.inner.as_ptr().replace(value) }
+    }
+
+    /// Takes the value, leaving `Default::default()` in its place.
+    #[inline]
+    pub fn take(self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+
+    /// Swaps the values of two locations.
+    #[inline]
+    pub fn swap(self, other: Self) {
+        if self.inner != other.inner {
+            // SAFETY: See `replace`, and the locations are distinct so the
+            // values can be moved out of them one after the other.
+            unsafe {
+                let value = self.inner.as_ptr().read();
+                self.inner.as_ptr().write(other.replace(value));
+            }
+        }
+    }
+}
+
+impl<T: Copy> AliasableCell<'_, T> {
+    /// Returns a copy of the value.
+    #[inline]
+    pub fn get(self) -> T {
+        // SAFETY: See `replace`, and `T` is `Copy` so reading it leaves the
+        // location intact.
+        unsafe { self.inner.as_ptr().read() }
+    }
+
+    /// Updates the value with `f` and returns the new value.
+    #[inline]
+    pub fn update<F>(self, f: F) -> T
+    where
+        F: FnOnce(T) -> T,
+    {
+        let value = f(self.get());
+        self.set(value);
+        value
+    }
+}
+
+impl<'a, T> From<&'a mut T> for AliasableCell<'a, T> {
+    fn from(ptr: &'a mut T) -> Self {
+        Self::from_unique(ptr)
+    }
+}
+
+impl<T> Clone for AliasableCell<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AliasableCell<'_, T> {}
+
+impl<T> PartialEq for AliasableCell<'_, T> {
+    /// Returns `true` if both handles point to the same location.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> Eq for AliasableCell<'_, T> {}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for AliasableCell<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AliasableCell")
+            .field("value", &self.get())
+            .finish()
+    }
+}
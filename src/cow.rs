@@ -0,0 +1,294 @@
+//! Aliasable clone-on-write smart pointer.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use alloc::borrow::{Cow, ToOwned};
+
+use crate::boxed::{AliasableBox, UniqueBox};
+use crate::string::{AliasableString, UniqueString};
+use crate::vec::{AliasableVec, UniqueVec};
+
+/// A borrowed type with an aliasable owned counterpart, used by
+/// [`AliasableCow`].
+///
+/// This is the aliasable equivalent of [`ToOwned`], whose `Owned` type is the
+/// unique counterpart.
+pub trait ToAliasable: ToOwned {
+    /// The aliasable owned type, such as [`AliasableString`] for `str`.
+    type Aliasable: Deref<Target = Self>;
+
+    /// Construct the aliasable owned type from the unique owned type.
+    fn from_unique(owned: Self::Owned) -> Self::Aliasable;
+
+    /// Converts the aliasable owned type back into the unique owned type.
+    fn into_unique(aliasable: Self::Aliasable) -> Self::Owned;
+
+    /// Creates aliasable owned data from borrowed data, usually by cloning.
+    fn to_aliasable(&self) -> Self::Aliasable {
+        Self::from_unique(self.to_owned())
+    }
+}
+
+impl<T: Clone> ToAliasable for T {
+    type Aliasable = AliasableBox<T>;
+
+    #[inline]
+    fn from_unique(owned: T) -> AliasableBox<T> {
+        AliasableBox::from_unique(UniqueBox::new(owned))
+    }
+
+    #[inline]
+    fn into_unique(aliasable: AliasableBox<T>) -> T {
+        *AliasableBox::into_unique(aliasable)
+    }
+}
+
+impl<T: Clone> ToAliasable for [T] {
+    type Aliasable = AliasableVec<T>;
+
+    #[inline]
+    fn from_unique(owned: UniqueVec<T>) -> AliasableVec<T> {
+        AliasableVec::from_unique(owned)
+    }
+
+    #[inline]
+    fn into_unique(aliasable: AliasableVec<T>) -> UniqueVec<T> {
+        AliasableVec::into_unique(aliasable)
+    }
+}
+
+impl ToAliasable for str {
+    type Aliasable = AliasableString;
+
+    #[inline]
+    fn from_unique(owned: UniqueString) -> AliasableString {
+        AliasableString::from_unique(owned)
+    }
+
+    #[inline]
+    fn into_unique(aliasable: AliasableString) -> UniqueString {
+        AliasableString::into_unique(aliasable)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ToAliasable for std::ffi::CStr {
+    type Aliasable = crate::ffi::AliasableCString;
+
+    #[inline]
+    fn from_unique(owned: std::ffi::CString) -> Self::Aliasable {
+        crate::ffi::AliasableCString::from_unique(owned)
+    }
+
+    #[inline]
+    fn into_unique(aliasable: Self::Aliasable) -> std::ffi::CString {
+        crate::ffi::AliasableCString::into_unique(aliasable)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ToAliasable for std::ffi::OsStr {
+    type Aliasable = crate::ffi::AliasableOsString;
+
+    #[inline]
+    fn from_unique(owned: std::ffi::OsString) -> Self::Aliasable {
+        crate::ffi::AliasableOsString::from_unique(owned)
+    }
+
+    #[inline]
+    fn into_unique(aliasable: Self::Aliasable) -> std::ffi::OsString {
+        crate::ffi::AliasableOsString::into_unique(aliasable)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ToAliasable for std::path::Path {
+    type Aliasable = crate::path::AliasablePathBuf;
+
+    #[inline]
+    fn from_unique(owned: std::path::PathBuf) -> Self::Aliasable {
+        crate::path::AliasablePathBuf::from_unique(owned)
+    }
+
+    #[inline]
+    fn into_unique(aliasable: Self::Aliasable) -> std::path::PathBuf {
+        crate::path::AliasablePathBuf::into_unique(aliasable)
+    }
+}
+
+/// Aliasable alternative to [`Cow`], whose owned data is stored in an
+/// aliasable (non `core::ptr::Unique`) type such as [`AliasableBox`],
+/// [`AliasableVec`] or [`AliasableString`].
+pub enum AliasableCow<'a, B: ?Sized + ToAliasable> {
+    /// Borrowed data.
+    Borrowed(&'a B),
+    /// Owned aliasable data.
+    Owned(B::Aliasable),
+}
+
+impl<'a, B: ?Sized + ToAliasable> AliasableCow<'a, B> {
+    /// Returns `true` if the data is borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, AliasableCow::Borrowed(_))
+    }
+
+    /// Returns `true` if the data is owned.
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Acquires a mutable reference to the owned form of the data, cloning
+    /// the data if it is borrowed.
+    pub fn to_mut(&mut self) -> &mut B::Aliasable {
+        if let AliasableCow::Borrowed(borrowed) = *self {
+            *self = AliasableCow::Owned(borrowed.to_aliasable());
+        }
+        match self {
+            AliasableCow::Owned(owned) => owned,
+            AliasableCow::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Extracts the owned data as the non-aliasable unique owned type,
+    /// cloning the data if it is borrowed.
+    pub fn into_owned(self) -> B::Owned {
+        match self {
+            AliasableCow::Borrowed(borrowed) => borrowed.to_owned(),
+            AliasableCow::Owned(owned) => B::into_unique(owned),
+        }
+    }
+
+    /// Construct an `AliasableCow` from a [`Cow`].
+    pub fn from_unique(cow: Cow<'a, B>) -> Self {
+        match cow {
+            Cow::Borrowed(borrowed) => AliasableCow::Borrowed(borrowed),
+            Cow::Owned(owned) => AliasableCow::Owned(B::from_unique(owned)),
+        }
+    }
+
+    /// Consumes the [`AliasableCow`] and converts it back into a
+    /// non-aliasable [`Cow`].
+    pub fn into_unique(aliasable: AliasableCow<'a, B>) -> Cow<'a, B> {
+        match aliasable {
+            AliasableCow::Borrowed(borrowed) => Cow::Borrowed(borrowed),
+            AliasableCow::Owned(owned) => Cow::Owned(B::into_unique(owned)),
+        }
+    }
+}
+
+impl<'a, B: ?Sized + ToAliasable> From<&'a B> for AliasableCow<'a, B> {
+    #[inline]
+    fn from(borrowed: &'a B) -> Self {
+        AliasableCow::Borrowed(borrowed)
+    }
+}
+
+impl<'a, B: ?Sized + ToAliasable> From<Cow<'a, B>> for AliasableCow<'a, B> {
+    #[inline]
+    fn from(cow: Cow<'a, B>) -> Self {
+        Self::from_unique(cow)
+    }
+}
+
+impl<'a, B: ?Sized + ToAliasable> From<AliasableCow<'a, B>> for Cow<'a, B> {
+    #[inline]
+    fn from(aliasable: AliasableCow<'a, B>) -> Self {
+        AliasableCow::into_unique(aliasable)
+    }
+}
+
+impl<B: ?Sized + ToAliasable> Deref for AliasableCow<'_, B> {
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &B {
+        match self {
+            AliasableCow::Borrowed(borrowed) => borrowed,
+            AliasableCow::Owned(owned) => owned,
+        }
+    }
+}
+
+impl<B: ?Sized + ToAliasable> AsRef<B> for AliasableCow<'_, B> {
+    #[inline]
+    fn as_ref(&self) -> &B {
+        self
+    }
+}
+
+impl<B: ?Sized + ToAliasable> Borrow<B> for AliasableCow<'_, B> {
+    #[inline]
+    fn borrow(&self) -> &B {
+        self
+    }
+}
+
+impl<B: ?Sized + ToAliasable> Clone for AliasableCow<'_, B> {
+    fn clone(&self) -> Self {
+        match self {
+            AliasableCow::Borrowed(borrowed) => AliasableCow::Borrowed(borrowed),
+            AliasableCow::Owned(owned) => AliasableCow::Owned((**owned).to_aliasable()),
+        }
+    }
+}
+
+impl<B: ?Sized + ToAliasable> fmt::Debug for AliasableCow<'_, B>
+where
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<B: ?Sized + ToAliasable> fmt::Display for AliasableCow<'_, B>
+where
+    B: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<B: ?Sized + ToAliasable> Default for AliasableCow<'_, B>
+where
+    B::Aliasable: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        AliasableCow::Owned(B::Aliasable::default())
+    }
+}
+
+impl<B: ?Sized + ToAliasable + PartialEq> PartialEq for AliasableCow<'_, B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<B: ?Sized + ToAliasable + Eq> Eq for AliasableCow<'_, B> {}
+
+impl<B: ?Sized + ToAliasable + PartialOrd> PartialOrd for AliasableCow<'_, B> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<B: ?Sized + ToAliasable + Ord> Ord for AliasableCow<'_, B> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<B: ?Sized + ToAliasable + Hash> Hash for AliasableCow<'_, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
@@ -0,0 +1,160 @@
+//! Cursor over an aliasable byte buffer.
+
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+use core::pin::Pin;
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+use core::task::{Context, Poll};
+
+/// Cursor tracking a read position within a byte buffer such as an
+/// `AliasableVec<u8>` or `AliasableBox<[u8]>`.
+///
+/// Reading through the cursor never moves or copies the underlying buffer.
+#[derive(Debug, Default, Clone)]
+pub struct Cursor<T> {
+    inner: T,
+    pos: usize,
+}
+
+impl<T> Cursor<T> {
+    /// Creates a new cursor wrapping the provided buffer, positioned at its
+    /// start.
+    pub fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Consumes the cursor, returning the underlying buffer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying buffer.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying buffer.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Returns the current position of the cursor.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the position of the cursor.
+    pub fn set_position(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+}
+
+impl<T: AsRef<[u8]>> Cursor<T> {
+    /// Returns the bytes of the buffer after the current position.
+    pub fn remaining_slice(&self) -> &[u8] {
+        let buf = self.inner.as_ref();
+        &buf[buf.len().min(self.pos)..]
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<T: AsRef<[u8]>> bytes::Buf for Cursor<T> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.remaining_slice().len()
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.remaining_slice()
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        let remaining = self.remaining();
+        assert!(
+            cnt <= remaining,
+            "cannot advance past `remaining`: {:?} <= {:?}",
+            cnt,
+            remaining
+        );
+        self.pos += cnt;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<[u8]>> std::io::Read for Cursor<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let src = self.remaining_slice();
+        let n = src.len().min(buf.len());
+        buf[..n].copy_from_slice(&src[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AsRef<[u8]>> std::io::BufRead for Cursor<T> {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<T: AsRef<[u8]> + Unpin> futures_io::AsyncRead for Cursor<T> {
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(std::io::Read::read(self.get_mut(), buf))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<T: AsRef<[u8]> + Unpin> futures_io::AsyncBufRead for Cursor<T> {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().remaining_slice()))
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().pos += amt;
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: AsRef<[u8]> + Unpin> tokio::io::AsyncRead for Cursor<T> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let src = this.remaining_slice();
+        let n = src.len().min(buf.remaining());
+        buf.put_slice(&src[..n]);
+        this.pos += n;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: AsRef<[u8]> + Unpin> tokio::io::AsyncBufRead for Cursor<T> {
+    #[inline]
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().remaining_slice()))
+    }
+
+    #[inline]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().pos += amt;
+    }
+}
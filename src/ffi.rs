@@ -0,0 +1,265 @@
+//! Aliasable `CString` and `OsString`.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::pin::Pin;
+use std::ffi::{CStr, OsStr};
+use std::os::raw::c_char;
+
+use crate::vec::AliasableVec;
+
+pub use std::ffi::CString as UniqueCString;
+pub use std::ffi::OsString as UniqueOsString;
+
+/// Basic aliasable (non `core::ptr::Unique`) alternative to
+/// [`std::ffi::CString`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AliasableCString(AliasableVec<u8>);
+
+impl AliasableCString {
+    /// Returns the inner pointer to this C string.
+    ///
+    /// The pointer remains valid for as long as `self` is not dropped, even
+    /// when `self` is moved.
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_char {
+        self.0.as_ptr().cast()
+    }
+
+    /// Extracts a [`CStr`] slice containing the entire string.
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        self
+    }
+
+    /// Consumes `self` into an [`AliasableVec`] of bytes, including the
+    /// trailing nul terminator.
+    pub fn into_bytes_with_nul(self) -> AliasableVec<u8> {
+        self.0
+    }
+
+    /// Construct an `AliasableCString` from a [`UniqueCString`].
+    pub fn from_unique(s: UniqueCString) -> Self {
+        Self(s.into_bytes_with_nul().into())
+    }
+
+    /// Consumes `self` and converts it into a non-aliasable [`UniqueCString`].
+    #[inline]
+    pub fn into_unique(s: AliasableCString) -> UniqueCString {
+        let unique_bytes = s.into_bytes_with_nul().into();
+        // SAFETY: `AliasableCString` will only ever contain a single trailing
+        // nul terminator.
+        unsafe { UniqueCString::from_vec_with_nul_unchecked(unique_bytes) }
+    }
+
+    /// Convert a pinned [`AliasableCString`] to a `core::ptr::Unique` backed
+    /// pinned [`UniqueCString`].
+    pub fn into_unique_pin(pin: Pin<AliasableCString>) -> Pin<UniqueCString> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let aliasable = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasableCString::into_unique(aliasable))
+        }
+    }
+
+    /// Convert a pinned `core::ptr::Unique` backed [`UniqueCString`] to a
+    /// pinned [`AliasableCString`].
+    pub fn from_unique_pin(pin: Pin<UniqueCString>) -> Pin<AliasableCString> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let unique = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasableCString::from(unique))
+        }
+    }
+}
+
+impl From<UniqueCString> for AliasableCString {
+    #[inline]
+    fn from(s: UniqueCString) -> Self {
+        Self::from_unique(s)
+    }
+}
+
+impl From<AliasableCString> for UniqueCString {
+    #[inline]
+    fn from(s: AliasableCString) -> Self {
+        AliasableCString::into_unique(s)
+    }
+}
+
+impl Deref for AliasableCString {
+    type Target = CStr;
+
+    #[inline]
+    fn deref(&self) -> &CStr {
+        // SAFETY: `AliasableCString` will only ever contain a single trailing
+        // nul terminator.
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.0) }
+    }
+}
+
+impl AsRef<CStr> for AliasableCString {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self
+    }
+}
+
+impl fmt::Debug for AliasableCString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), f)
+    }
+}
+
+impl Default for AliasableCString {
+    #[inline]
+    fn default() -> Self {
+        Self::from_unique(UniqueCString::default())
+    }
+}
+
+impl Clone for AliasableCString {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl crate::StableDeref for AliasableCString {}
+
+#[cfg(feature = "aliasable_deref_trait")]
+unsafe impl crate::AliasableDeref for AliasableCString {}
+
+/// Basic aliasable (non `core::ptr::Unique`) alternative to
+/// [`std::ffi::OsString`].
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct AliasableOsString(AliasableVec<u8>);
+
+impl AliasableOsString {
+    /// Extracts an [`OsStr`] slice containing the entire string.
+    #[inline]
+    pub fn as_os_str(&self) -> &OsStr {
+        self
+    }
+
+    /// Consumes `self` into an [`AliasableVec`] of the platform's encoded
+    /// bytes.
+    ///
+    /// See [`OsStr::as_encoded_bytes`] for what the encoding guarantees.
+    pub fn into_encoded_bytes(self) -> AliasableVec<u8> {
+        self.0
+    }
+
+    /// Construct an `AliasableOsString` from a [`UniqueOsString`].
+    pub fn from_unique(s: UniqueOsString) -> Self {
+        Self(s.into_encoded_bytes().into())
+    }
+
+    /// Consumes `self` and converts it into a non-aliasable
+    /// [`UniqueOsString`].
+    #[inline]
+    pub fn into_unique(s: AliasableOsString) -> UniqueOsString {
+        let unique_bytes = s.into_encoded_bytes().into();
+        // SAFETY: `AliasableOsString` will only ever contain bytes encoded
+        // by an `OsString` on this platform.
+        unsafe { UniqueOsString::from_encoded_bytes_unchecked(unique_bytes) }
+    }
+
+    /// Convert a pinned [`AliasableOsString`] to a `core::ptr::Unique` backed
+    /// pinned [`UniqueOsString`].
+    pub fn into_unique_pin(pin: Pin<AliasableOsString>) -> Pin<UniqueOsString> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let aliasable = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasableOsString::into_unique(aliasable))
+        }
+    }
+
+    /// Convert a pinned `core::ptr::Unique` backed [`UniqueOsString`] to a
+    /// pinned [`AliasableOsString`].
+    pub fn from_unique_pin(pin: Pin<UniqueOsString>) -> Pin<AliasableOsString> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let unique = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasableOsString::from(unique))
+        }
+    }
+}
+
+impl From<UniqueOsString> for AliasableOsString {
+    #[inline]
+    fn from(s: UniqueOsString) -> Self {
+        Self::from_unique(s)
+    }
+}
+
+impl From<AliasableOsString> for UniqueOsString {
+    #[inline]
+    fn from(s: AliasableOsString) -> Self {
+        AliasableOsString::into_unique(s)
+    }
+}
+
+impl Deref for AliasableOsString {
+    type Target = OsStr;
+
+    #[inline]
+    fn deref(&self) -> &OsStr {
+        // SAFETY: `AliasableOsString` will only ever contain bytes encoded by
+        // an `OsString` on this platform.
+        unsafe { OsStr::from_encoded_bytes_unchecked(&self.0) }
+    }
+}
+
+impl AsRef<OsStr> for AliasableOsString {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        self
+    }
+}
+
+impl fmt::Debug for AliasableOsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), f)
+    }
+}
+
+impl Default for AliasableOsString {
+    #[inline]
+    fn default() -> Self {
+        Self::from_unique(UniqueOsString::default())
+    }
+}
+
+impl Clone for AliasableOsString {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+// Deriving `Hash` would be incorrect because it would hash as bytes and not an
+// `OsStr`.
+#[allow(clippy::derived_hash_with_manual_eq)]
+impl Hash for AliasableOsString {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        (**self).hash(hasher);
+    }
+}
+
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl crate::StableDeref for AliasableOsString {}
+
+#[cfg(feature = "aliasable_deref_trait")]
+unsafe impl crate::AliasableDeref for AliasableOsString {}
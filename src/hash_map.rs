@@ -0,0 +1,415 @@
+//! Aliasable `HashMap`, backed by [`hashbrown`].
+
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::ops::Index;
+
+use hashbrown::hash_map;
+use hashbrown::{DefaultHashBuilder, Equivalent};
+
+use crate::boxed::{AliasableBox, UniqueBox};
+
+pub use hashbrown::HashMap as UniqueHashMap;
+
+/// Hash map whose values are each stored in their own [`AliasableBox`].
+///
+/// Unlike [`hashbrown::HashMap`], values are never moved by the map itself, so
+/// references and raw pointers to a value stay valid across insertions and
+/// removals of other entries and rehashing. Raw pointers from
+/// [`AliasableHashMap::get_ptr`] also stay valid across replacing the value
+/// with [`AliasableHashMap::insert`], which writes through the same pointer.
+pub struct AliasableHashMap<K, V, S = DefaultHashBuilder>(UniqueHashMap<K, AliasableBox<V>, S>);
+
+impl<K, V> AliasableHashMap<K, V, DefaultHashBuilder> {
+    /// Creates an empty `AliasableHashMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, V, S> AliasableHashMap<K, V, S> {
+    /// Creates an empty `AliasableHashMap` which will use the given hash
+    /// builder to hash keys.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self(UniqueHashMap::with_hasher(hash_builder))
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Clears the map, removing all elements.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    pub fn hasher(&self) -> &S {
+        self.0.hasher()
+    }
+
+    /// Gets an iterator over the entries of the map, in arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.0.iter())
+    }
+
+    /// Gets a mutable iterator over the entries of the map, in arbitrary
+    /// order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut(self.0.iter_mut())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> AliasableHashMap<K, V, S> {
+    /// Construct an `AliasableHashMap` from a [`UniqueHashMap`].
+    ///
+    /// This moves every value into its own allocation.
+    pub fn from_unique(unique: UniqueHashMap<K, V, S>) -> Self
+    where
+        S: Clone,
+    {
+        let mut map =
+            UniqueHashMap::with_capacity_and_hasher(unique.len(), unique.hasher().clone());
+        map.extend(
+            unique
+                .into_iter()
+                .map(|(k, v)| (k, AliasableBox::from_unique(UniqueBox::new(v)))),
+        );
+        Self(map)
+    }
+
+    /// Consumes the [`AliasableHashMap`] and converts it back into a
+    /// non-aliasable [`UniqueHashMap`].
+    ///
+    /// This moves every value out of its own allocation.
+    pub fn into_unique(aliasable: AliasableHashMap<K, V, S>) -> UniqueHashMap<K, V, S>
+    where
+        S: Clone,
+    {
+        let mut map =
+            UniqueHashMap::with_capacity_and_hasher(aliasable.len(), aliasable.hasher().clone());
+        map.extend(
+            aliasable
+                .0
+                .into_iter()
+                .map(|(k, v)| (k, *AliasableBox::into_unique(v))),
+        );
+        map
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0.get(key).map(|v| &**v)
+    }
+
+    /// Returns a raw pointer to the value corresponding to the key.
+    ///
+    /// The pointer stays valid until the entry is removed, including across
+    /// replacing the value with [`AliasableHashMap::insert`].
+    pub fn get_ptr<Q>(&self, key: &Q) -> Option<*const V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0
+            .get(key)
+            .map(|v| AliasableBox::as_raw(v).as_ptr().cast_const())
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0.get_mut(key).map(|v| &mut **v)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0.contains_key(key)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already had a value for the key, the value is replaced in
+    /// place, keeping its address, and the old value is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                let _ = entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0.remove(key).map(|v| *AliasableBox::into_unique(v))
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        match self.0.entry(key) {
+            hash_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry(entry)),
+            hash_map::Entry::Vacant(entry) => Entry::Vacant(VacantEntry(entry)),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> From<UniqueHashMap<K, V, S>>
+    for AliasableHashMap<K, V, S>
+{
+    #[inline]
+    fn from(unique: UniqueHashMap<K, V, S>) -> Self {
+        Self::from_unique(unique)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> From<AliasableHashMap<K, V, S>>
+    for UniqueHashMap<K, V, S>
+{
+    #[inline]
+    fn from(aliasable: AliasableHashMap<K, V, S>) -> Self {
+        AliasableHashMap::into_unique(aliasable)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> FromIterator<(K, V)> for AliasableHashMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Extend<(K, V)> for AliasableHashMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            let _ = self.insert(k, v);
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a AliasableHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut AliasableHashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, Q, V, S> Index<&Q> for AliasableHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    Q: Hash + Equivalent<K> + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for AliasableHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S: Default> Default for AliasableHashMap<K, V, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for AliasableHashMap<K, V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq for AliasableHashMap<K, V, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq + Hash, V: Eq, S: BuildHasher> Eq for AliasableHashMap<K, V, S> {}
+
+/// An iterator over the entries of an [`AliasableHashMap`].
+pub struct Iter<'a, K, V>(hash_map::Iter<'a, K, AliasableBox<V>>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, &**v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A mutable iterator over the entries of an [`AliasableHashMap`].
+pub struct IterMut<'a, K, V>(hash_map::IterMut<'a, K, AliasableBox<V>>);
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, &mut **v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// A view into a single entry in an [`AliasableHashMap`], which may either be
+/// vacant or occupied.
+pub enum Entry<'a, K, V, S> {
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V, S>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, S>),
+}
+
+impl<'a, K: Hash, V, S: BuildHasher> Entry<'a, K, V, S> {
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Hash, V: Default, S: BuildHasher> Entry<'a, K, V, S> {
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// A view into a vacant entry in an [`AliasableHashMap`].
+pub struct VacantEntry<'a, K, V, S>(hash_map::VacantEntry<'a, K, AliasableBox<V>, S>);
+
+impl<'a, K: Hash, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the `VacantEntry`.
+    pub fn key(&self) -> &K {
+        self.0.key()
+    }
+
+    /// Take ownership of the key.
+    pub fn into_key(self) -> K {
+        self.0.into_key()
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, and returns a
+    /// mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.0
+            .insert(AliasableBox::from_unique(UniqueBox::new(value)))
+    }
+}
+
+/// A view into an occupied entry in an [`AliasableHashMap`].
+pub struct OccupiedEntry<'a, K, V, S>(hash_map::OccupiedEntry<'a, K, AliasableBox<V>, S>);
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    /// Gets a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        self.0.key()
+    }
+
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self) -> &V {
+        self.0.get()
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.0.get_mut()
+    }
+
+    /// Converts the entry into a mutable reference to its value.
+    pub fn into_mut(self) -> &'a mut V {
+        self.0.into_mut()
+    }
+
+    /// Sets the value of the entry in place, keeping its address, and returns
+    /// the entry's old value.
+    pub fn insert(&mut self, value: V) -> V {
+        AliasableBox::replace(self.0.get_mut(), value)
+    }
+
+    /// Takes the value of the entry out of the map, and returns it.
+    pub fn remove(self) -> V {
+        *AliasableBox::into_unique(self.0.remove())
+    }
+}
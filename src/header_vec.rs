@@ -0,0 +1,345 @@
+//! Aliasable header followed by a growable slice in a single allocation.
+
+use core::alloc::Layout;
+use core::cmp::{self, Ordering};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ptr::{self, NonNull};
+use core::{fmt, slice};
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, realloc};
+
+use crate::vec::UniqueVec;
+
+/// Aliasable (non `core::ptr::Unique`) allocation holding a header of type `H`
+/// followed by a growable tail of `T`s, like an [`AliasableVec`] with a fixed
+/// prefix.
+///
+/// Pointers to the header and to the tail elements stay valid as long as the
+/// tail has spare capacity. Growing the tail may move the whole allocation,
+/// header included.
+///
+/// [`AliasableVec`]: crate::vec::AliasableVec
+pub struct AliasableHeaderVec<H, T> {
+    ptr: NonNull<u8>,
+    len: usize,
+    cap: usize,
+    // The allocation owns a `H` and the `T`s.
+    _marker: PhantomData<(H, T)>,
+}
+
+impl<H, T> AliasableHeaderVec<H, T> {
+    /// Constructs a new `AliasableHeaderVec` with the given header and an
+    /// empty tail.
+    pub fn new(header: H) -> Self {
+        Self::with_capacity(header, 0)
+    }
+
+    /// Constructs a new `AliasableHeaderVec` with the given header and an
+    /// empty tail which can hold at least `capacity` elements without
+    /// reallocating.
+    pub fn with_capacity(header: H, capacity: usize) -> Self {
+        let cap = if size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            capacity
+        };
+        let ptr = allocate(layout_for::<H, T>(cap).0);
+        // SAFETY: The allocation starts with room for the header.
+        unsafe { ptr.cast::<H>().as_ptr().write(header) };
+        Self {
+            ptr,
+            len: 0,
+            cap,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Construct an `AliasableHeaderVec` from a header and the elements of a
+    /// [`UniqueVec`], which are moved into the new allocation.
+    pub fn from_parts(header: H, tail: UniqueVec<T>) -> Self {
+        let mut this = Self::with_capacity(header, tail.len());
+        this.extend(tail);
+        this
+    }
+
+    /// Consumes the [`AliasableHeaderVec`] and splits it back into its header
+    /// and a non-aliasable [`UniqueVec`] of the tail elements.
+    pub fn into_parts(aliasable: AliasableHeaderVec<H, T>) -> (H, UniqueVec<T>) {
+        // Ensure we don't drop `self` as we are moving its contents out and we
+        // don't want a double drop.
+        let aliasable = ManuallyDrop::new(aliasable);
+        let mut tail = UniqueVec::with_capacity(aliasable.len);
+        // SAFETY: The header and elements are initialized and are moved out,
+        // after which the allocation is freed without dropping them.
+        unsafe {
+            let header = aliasable.as_header_ptr().read();
+            ptr::copy_nonoverlapping(aliasable.as_ptr(), tail.as_mut_ptr(), aliasable.len);
+            tail.set_len(aliasable.len);
+            deallocate(aliasable.ptr, layout_for::<H, T>(aliasable.cap).0);
+            (header, tail)
+        }
+    }
+
+    /// Returns the number of elements in the tail.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the tail can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns `true` if the tail contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the header.
+    pub fn header(&self) -> &H {
+        // SAFETY: The header is always initialized.
+        unsafe { &*self.as_header_ptr() }
+    }
+
+    /// Returns a mutable reference to the header.
+    pub fn header_mut(&mut self) -> &mut H {
+        // SAFETY: The header is always initialized.
+        unsafe { &mut *self.as_mut_header_ptr() }
+    }
+
+    /// Returns the tail as a slice.
+    pub fn slice(&self) -> &[T] {
+        // SAFETY: The first `len` elements of the tail are initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns the tail as a mutable slice.
+    pub fn slice_mut(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` elements of the tail are initialized.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Returns a raw pointer to the header.
+    pub fn as_header_ptr(&self) -> *const H {
+        self.ptr.cast::<H>().as_ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the header.
+    pub fn as_mut_header_ptr(&mut self) -> *mut H {
+        self.ptr.cast::<H>().as_ptr()
+    }
+
+    /// Returns a raw pointer to the tail's buffer.
+    pub fn as_ptr(&self) -> *const T {
+        self.tail_ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the tail's buffer.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.tail_ptr()
+    }
+
+    /// Reserves capacity for at least `additional` more elements in the tail.
+    ///
+    /// This may move the whole allocation, header included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.cap {
+            return;
+        }
+        let doubled = self.cap.checked_mul(2).expect("capacity overflow");
+        let new_cap = cmp::max(cmp::max(required, doubled), 4);
+        let old_layout = layout_for::<H, T>(self.cap).0;
+        let new_layout = layout_for::<H, T>(new_cap).0;
+        // SAFETY: Both layouts have the same alignment and the header offset
+        // never changes, so the header and elements are carried over as is.
+        self.ptr = unsafe {
+            if old_layout.size() == 0 {
+                let new = allocate(new_layout);
+                ptr::copy_nonoverlapping(self.as_header_ptr(), new.cast::<H>().as_ptr(), 1);
+                new
+            } else {
+                let new = realloc(self.ptr.as_ptr(), old_layout, new_layout.size());
+                NonNull::new(new).unwrap_or_else(|| handle_alloc_error(new_layout))
+            }
+        };
+        self.cap = new_cap;
+    }
+
+    /// Appends an element to the back of the tail.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+        // SAFETY: There is spare capacity, so the slot after the last element
+        // is uninitialized.
+        unsafe { self.tail_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// Removes the last element of the tail and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The slot was initialized and is no longer tracked as such.
+        Some(unsafe { self.tail_ptr().add(self.len).read() })
+    }
+
+    /// Shortens the tail, keeping the first `len` elements and dropping the
+    /// rest.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len` is within the initialized elements.
+            unsafe { self.tail_ptr().add(len) },
+            self.len - len,
+        );
+        // Update the length first, so a panicking drop never leaves it covering
+        // a dropped element.
+        self.len = len;
+        // SAFETY: The elements were initialized and are no longer tracked.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Clears the tail, removing all its elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Clones and appends all elements in `other` to the tail.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.extend(other.iter().cloned());
+    }
+
+    #[inline]
+    fn tail_ptr(&self) -> *mut T {
+        let offset = layout_for::<H, T>(0).1;
+        // SAFETY: The tail always starts at the same offset, which is within
+        // or one past the end of the allocation.
+        unsafe { self.ptr.as_ptr().add(offset).cast::<T>() }
+    }
+}
+
+impl<H, T> Drop for AliasableHeaderVec<H, T> {
+    fn drop(&mut self) {
+        // SAFETY: As `self` is being dropped we can safely assume any aliasing
+        // has ended.
+        unsafe {
+            ptr::drop_in_place(self.as_mut_header_ptr());
+            ptr::drop_in_place(self.slice_mut());
+            deallocate(self.ptr, layout_for::<H, T>(self.cap).0);
+        }
+    }
+}
+
+impl<H, T> Extend<T> for AliasableHeaderVec<H, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|value| self.push(value));
+    }
+}
+
+impl<H, T> fmt::Debug for AliasableHeaderVec<H, T>
+where
+    H: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AliasableHeaderVec")
+            .field("header", self.header())
+            .field("slice", &self.slice())
+            .finish()
+    }
+}
+
+impl<H: Default, T> Default for AliasableHeaderVec<H, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(H::default())
+    }
+}
+
+impl<H: Clone, T: Clone> Clone for AliasableHeaderVec<H, T> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::with_capacity(self.header().clone(), self.len);
+        clone.extend_from_slice(self.slice());
+        clone
+    }
+}
+
+impl<H: PartialEq, T: PartialEq> PartialEq for AliasableHeaderVec<H, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.header() == other.header() && self.slice() == other.slice()
+    }
+}
+
+impl<H: Eq, T: Eq> Eq for AliasableHeaderVec<H, T> {}
+
+impl<H: PartialOrd, T: PartialOrd> PartialOrd for AliasableHeaderVec<H, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.header(), self.slice()).partial_cmp(&(other.header(), other.slice()))
+    }
+}
+
+impl<H: Ord, T: Ord> Ord for AliasableHeaderVec<H, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.header(), self.slice()).cmp(&(other.header(), other.slice()))
+    }
+}
+
+impl<H: Hash, T: Hash> Hash for AliasableHeaderVec<H, T> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.header().hash(state);
+        self.slice().hash(state);
+    }
+}
+
+unsafe impl<H: Send, T: Send> Send for AliasableHeaderVec<H, T> {}
+unsafe impl<H: Sync, T: Sync> Sync for AliasableHeaderVec<H, T> {}
+
+/// Returns the layout of an allocation holding a `H` followed by `cap` `T`s,
+/// and the offset of the first `T` within it.
+fn layout_for<H, T>(cap: usize) -> (Layout, usize) {
+    let (layout, offset) = Layout::array::<T>(cap)
+        .and_then(|tail| Layout::new::<H>().extend(tail))
+        .expect("capacity overflow");
+    (layout.pad_to_align(), offset)
+}
+
+/// Allocates memory for `layout`, returning a dangling but aligned pointer if
+/// it is zero-sized.
+fn allocate(layout: Layout) -> NonNull<u8> {
+    if layout.size() == 0 {
+        // SAFETY: The alignment of a layout is never zero.
+        return unsafe {
+            NonNull::new_unchecked(ptr::null_mut::<u8>().wrapping_add(layout.align()))
+        };
+    }
+    // SAFETY: The layout is not zero-sized.
+    NonNull::new(unsafe { alloc(layout) }).unwrap_or_else(|| handle_alloc_error(layout))
+}
+
+/// Frees memory returned by [`allocate`] for the same `layout`.
+unsafe fn deallocate(ptr: NonNull<u8>, layout: Layout) {
+    if layout.size() != 0 {
+        dealloc(ptr.as_ptr(), layout);
+    }
+}
@@ -0,0 +1,491 @@
+//! Intrusive doubly-linked list of pinned aliasable nodes.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+use crate::boxed::{AliasableBox, UniqueBox};
+
+type Link<T> = Option<NonNull<ListNode<T>>>;
+
+/// A node of an [`IntrusiveList`], holding a value along with the links to
+/// its neighbours.
+///
+/// Nodes are allocated as `Pin<AliasableBox<ListNode<T>>>`, so they keep their
+/// address while their neighbours point to them, and moving the box in and out
+/// of a list never asserts unique access to the links the list writes through
+/// those pointers.
+pub struct ListNode<T> {
+    prev: Link<T>,
+    next: Link<T>,
+    value: T,
+    _pinned: PhantomPinned,
+}
+
+impl<T> ListNode<T> {
+    /// Allocates an unlinked node holding `value`.
+    pub fn new(value: T) -> Pin<AliasableBox<Self>> {
+        AliasableBox::from_unique_pin(UniqueBox::pin(Self {
+            prev: None,
+            next: None,
+            value,
+            _pinned: PhantomPinned,
+        }))
+    }
+
+    /// Returns a reference to the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value, which is not itself pinned.
+    pub fn value_mut(self: Pin<&mut Self>) -> &mut T {
+        // SAFETY: Only the node is pinned, the value is never treated as such.
+        unsafe { &mut self.get_unchecked_mut().value }
+    }
+
+    /// Consumes the node and returns its value.
+    pub fn into_inner(node: Pin<AliasableBox<Self>>) -> T {
+        // SAFETY: Nodes outside of a list aren't pointed to, and the value is
+        // not pinned.
+        let node = unsafe { Pin::into_inner_unchecked(node) };
+        AliasableBox::into_unique(node).value
+    }
+}
+
+impl<T> fmt::Debug for ListNode<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+// SAFETY: The links are only followed by the list owning the node.
+unsafe impl<T: Send> Send for ListNode<T> {}
+// SAFETY: The links are only followed by the list owning the node.
+unsafe impl<T: Sync> Sync for ListNode<T> {}
+
+/// A handle to a node in an [`IntrusiveList`], for finding or unlinking it in
+/// constant time.
+///
+/// As nodes never move, the handle stays the same when its node is removed
+/// from a list and inserted again.
+pub struct ListHandle<T>(NonNull<ListNode<T>>);
+
+impl<T> Clone for ListHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListHandle<T> {}
+
+impl<T> PartialEq for ListHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for ListHandle<T> {}
+
+impl<T> fmt::Debug for ListHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ListHandle").field(&self.0).finish()
+    }
+}
+
+/// Intrusive doubly-linked list owning `Pin<AliasableBox<ListNode<T>>>`s.
+///
+/// The nodes point to each other with raw pointers, which stay valid as the
+/// boxes are moved in and out of the list since [`AliasableBox`] never asserts
+/// unique access to its allocation. Inserting a node returns a [`ListHandle`]
+/// which can be used to unlink it again in constant time, such as to move an
+/// entry to the front of an LRU cache.
+pub struct IntrusiveList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    // We own the nodes.
+    _marker: PhantomData<Pin<AliasableBox<ListNode<T>>>>,
+}
+
+impl<T> IntrusiveList<T> {
+    /// Constructs a new, empty `IntrusiveList`.
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of nodes in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the value of the first node, or `None` if the
+    /// list is empty.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: The node is owned by the list.
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a reference to the value of the last node, or `None` if the
+    /// list is empty.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: The node is owned by the list.
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Links a node at the front of the list.
+    pub fn push_front(&mut self, node: Pin<AliasableBox<ListNode<T>>>) -> ListHandle<T> {
+        // SAFETY: Nothing comes before the head.
+        unsafe { self.link(node, None, self.head) }
+    }
+
+    /// Links a node at the back of the list.
+    pub fn push_back(&mut self, node: Pin<AliasableBox<ListNode<T>>>) -> ListHandle<T> {
+        // SAFETY: Nothing comes after the tail.
+        unsafe { self.link(node, self.tail, None) }
+    }
+
+    /// Unlinks the first node and returns it, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<Pin<AliasableBox<ListNode<T>>>> {
+        // SAFETY: The node is owned by the list.
+        self.head.map(|node| unsafe { self.unlink(node) })
+    }
+
+    /// Unlinks the last node and returns it, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<Pin<AliasableBox<ListNode<T>>>> {
+        // SAFETY: The node is owned by the list.
+        self.tail.map(|node| unsafe { self.unlink(node) })
+    }
+
+    /// Unlinks the node of `handle` and returns it.
+    ///
+    /// # Safety
+    ///
+    /// The node of `handle` must be in this list.
+    pub unsafe fn remove(&mut self, handle: ListHandle<T>) -> Pin<AliasableBox<ListNode<T>>> {
+        self.unlink(handle.0)
+    }
+
+    /// Unlinks and drops all the nodes.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Returns a cursor pointing to the first node, or to the "ghost"
+    /// position between the back and the front if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> ListCursorMut<'_, T> {
+        ListCursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing to the last node, or to the "ghost" position
+    /// between the back and the front if the list is empty.
+    pub fn cursor_back_mut(&mut self) -> ListCursorMut<'_, T> {
+        ListCursorMut {
+            current: self.tail,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing to the node of `handle`.
+    ///
+    /// # Safety
+    ///
+    /// The node of `handle` must be in this list.
+    pub unsafe fn cursor_from_handle(&mut self, handle: ListHandle<T>) -> ListCursorMut<'_, T> {
+        ListCursorMut {
+            current: Some(handle.0),
+            list: self,
+        }
+    }
+
+    /// Returns an iterator over the values of the nodes, from front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Links `node` between `prev` and `next`.
+    ///
+    /// # Safety
+    ///
+    /// `prev` and `next` must be adjacent nodes of this list, where `None`
+    /// stands for the ends of the list.
+    unsafe fn link(
+        &mut self,
+        node: Pin<AliasableBox<ListNode<T>>>,
+        prev: Link<T>,
+        next: Link<T>,
+    ) -> ListHandle<T> {
+        // The node is never moved out of its allocation while in the list.
+        let node = AliasableBox::into_raw(Pin::into_inner_unchecked(node));
+        (*node.as_ptr()).prev = prev;
+        (*node.as_ptr()).next = next;
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = Some(node),
+            None => self.head = Some(node),
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = Some(node),
+            None => self.tail = Some(node),
+        }
+        self.len += 1;
+        ListHandle(node)
+    }
+
+    /// Unlinks `node` and hands its ownership back.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be in this list.
+    unsafe fn unlink(&mut self, node: NonNull<ListNode<T>>) -> Pin<AliasableBox<ListNode<T>>> {
+        let prev = (*node.as_ptr()).prev.take();
+        let next = (*node.as_ptr()).next.take();
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+        Pin::new_unchecked(AliasableBox::from_raw(node))
+    }
+}
+
+impl<T> Drop for IntrusiveList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for IntrusiveList<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for IntrusiveList<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntrusiveList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+// SAFETY: The list owns its nodes.
+unsafe impl<T: Send> Send for IntrusiveList<T> {}
+// SAFETY: The list owns its nodes.
+unsafe impl<T: Sync> Sync for IntrusiveList<T> {}
+
+/// A cursor over an [`IntrusiveList`] which can insert and remove nodes.
+///
+/// Besides the nodes, the cursor can point to a "ghost" position between the
+/// back and the front of the list.
+pub struct ListCursorMut<'a, T> {
+    current: Link<T>,
+    list: &'a mut IntrusiveList<T>,
+}
+
+impl<T> ListCursorMut<'_, T> {
+    /// Returns a mutable reference to the value of the current node, or `None`
+    /// at the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: The node is owned by the list, which we borrow mutably, and
+        // the reference only covers the value and not the links.
+        self.current
+            .map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Returns the handle of the current node, or `None` at the ghost
+    /// position.
+    pub fn handle(&self) -> Option<ListHandle<T>> {
+        self.current.map(ListHandle)
+    }
+
+    /// Moves to the next node, or from the back to the ghost position and
+    /// from the ghost position to the front.
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            // SAFETY: The node is owned by the list.
+            Some(node) => unsafe { (*node.as_ptr()).next },
+            None => self.list.head,
+        };
+    }
+
+    /// Moves to the previous node, or from the front to the ghost position
+    /// and from the ghost position to the back.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            // SAFETY: The node is owned by the list.
+            Some(node) => unsafe { (*node.as_ptr()).prev },
+            None => self.list.tail,
+        };
+    }
+
+    /// Links a node before the current one, or at the back of the list at the
+    /// ghost position.
+    pub fn insert_before(&mut self, node: Pin<AliasableBox<ListNode<T>>>) -> ListHandle<T> {
+        let prev = self.prev();
+        // SAFETY: `prev` comes right before the current node.
+        unsafe { self.list.link(node, prev, self.current) }
+    }
+
+    /// Links a node after the current one, or at the front of the list at the
+    /// ghost position.
+    pub fn insert_after(&mut self, node: Pin<AliasableBox<ListNode<T>>>) -> ListHandle<T> {
+        let next = self.next();
+        // SAFETY: `next` comes right after the current node.
+        unsafe { self.list.link(node, self.current, next) }
+    }
+
+    /// Unlinks the current node and returns it, moving to the next node, or
+    /// returns `None` at the ghost position.
+    pub fn remove_current(&mut self) -> Option<Pin<AliasableBox<ListNode<T>>>> {
+        let node = self.current?;
+        self.current = self.next();
+        // SAFETY: The node is owned by the list.
+        Some(unsafe { self.list.unlink(node) })
+    }
+
+    /// Moves all the nodes of `other` before the current one, or at the back
+    /// of the list at the ghost position.
+    pub fn splice(&mut self, mut other: IntrusiveList<T>) {
+        let (Some(first), Some(last)) = (other.head, other.tail) else {
+            return;
+        };
+        let prev = self.prev();
+        // SAFETY: The nodes of `other` are taken over by the list, and `prev`
+        // comes right before the current node.
+        unsafe {
+            (*first.as_ptr()).prev = prev;
+            (*last.as_ptr()).next = self.current;
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = Some(first),
+                None => self.list.head = Some(first),
+            }
+            match self.current {
+                Some(current) => (*current.as_ptr()).prev = Some(last),
+                None => self.list.tail = Some(last),
+            }
+        }
+        self.list.len += other.len;
+        // Forget the nodes, so `other` doesn't drop them.
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    fn prev(&self) -> Link<T> {
+        match self.current {
+            // SAFETY: The node is owned by the list.
+            Some(node) => unsafe { (*node.as_ptr()).prev },
+            None => self.list.tail,
+        }
+    }
+
+    fn next(&self) -> Link<T> {
+        match self.current {
+            // SAFETY: The node is owned by the list.
+            Some(node) => unsafe { (*node.as_ptr()).next },
+            None => self.list.head,
+        }
+    }
+}
+
+// SAFETY: The cursor borrows the list mutably.
+unsafe impl<T: Send> Send for ListCursorMut<'_, T> {}
+// SAFETY: The cursor borrows the list mutably.
+unsafe impl<T: Sync> Sync for ListCursorMut<'_, T> {}
+
+/// An iterator over the values of an [`IntrusiveList`].
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a ListNode<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.head?;
+        self.len -= 1;
+        // SAFETY: The node is owned by the list, which we borrow.
+        unsafe {
+            self.head = (*node.as_ptr()).next;
+            Some(&(*node.as_ptr()).value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.tail?;
+        self.len -= 1;
+        // SAFETY: The node is owned by the list, which we borrow.
+        unsafe {
+            self.tail = (*node.as_ptr()).prev;
+            Some(&(*node.as_ptr()).value)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+// SAFETY: The iterator only hands out shared references.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: The iterator only hands out shared references.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
@@ -0,0 +1,52 @@
+//! Owned buffers for completion-based I/O.
+
+/// An owned byte buffer whose memory can be handed to the operating system
+/// for the duration of a completion-based I/O operation, such as with
+/// `io_uring`.
+///
+/// The buffer is moved into the operation and handed back when it completes,
+/// so its memory must stay put even as the buffer itself is moved around.
+///
+/// # Safety
+///
+/// The pointer returned by [`IoBuf::as_ptr`] must stay valid for reads of
+/// [`IoBuf::capacity`] bytes, and must not change when the buffer is moved,
+/// for as long as the buffer is not mutated through its own API. The first
+/// [`IoBuf::len`] bytes must be initialized.
+pub unsafe trait IoBuf: Unpin + 'static {
+    /// Returns a raw pointer to the start of the buffer.
+    fn as_ptr(&self) -> *const u8;
+
+    /// Returns the number of initialized bytes in the buffer.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the buffer has no initialized bytes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total number of bytes the buffer can hold.
+    fn capacity(&self) -> usize;
+}
+
+/// An owned byte buffer which can be written to by a completion-based I/O
+/// operation.
+///
+/// # Safety
+///
+/// In addition to the requirements of [`IoBuf`], the pointer returned by
+/// [`IoBufMut::as_mut_ptr`] must be the same as [`IoBuf::as_ptr`] and valid
+/// for writes of [`IoBuf::capacity`] bytes.
+pub unsafe trait IoBufMut: IoBuf {
+    /// Returns a raw mutable pointer to the start of the buffer.
+    fn as_mut_ptr(&mut self) -> *mut u8;
+
+    /// Marks the first `len` bytes of the buffer as initialized, once an
+    /// operation has written to them.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not be greater than [`IoBuf::capacity`], and the first `len`
+    /// bytes must have been initialized.
+    unsafe fn set_len(&mut self, len: usize);
+}
@@ -21,6 +21,11 @@
     unused_results,
     warnings
 )]
+#![cfg_attr(
+    aliasable_nightly,
+    feature(coerce_unsized, dispatch_from_dyn, pin_coerce_unsized_trait, unsize),
+    allow(unstable_features)
+)]
 #![allow(
     clippy::needless_pass_by_value,
     clippy::wrong_self_convention,
@@ -28,28 +33,96 @@
     clippy::module_name_repetitions
 )]
 
-#[cfg(any(test, feature = "alloc"))]
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[macro_use]
+mod macros;
 mod mut_ref;
 
+#[cfg(feature = "alloc")]
+pub mod arena;
+pub mod array_string;
+pub mod array_vec;
 #[cfg(feature = "alloc")]
 pub mod boxed;
 #[cfg(feature = "alloc")]
+pub mod btree_map;
+#[cfg(feature = "bytemuck")]
+pub mod cast;
+pub mod cell;
+#[cfg(feature = "alloc")]
+pub mod cow;
+pub mod cursor;
+#[cfg(feature = "std")]
+pub mod ffi;
+#[cfg(all(feature = "alloc", feature = "hashbrown"))]
+pub mod hash_map;
+#[cfg(feature = "alloc")]
+pub mod header_vec;
+#[cfg(feature = "alloc")]
+pub mod intrusive_list;
+pub mod io_buf;
+#[cfg(all(feature = "stable_deref_trait", feature = "aliasable_deref_trait"))]
+pub mod owning_ref;
+#[cfg(feature = "std")]
+pub mod path;
+#[cfg(feature = "alloc")]
+pub mod small_vec;
+#[cfg(feature = "alloc")]
 pub mod string;
 #[cfg(feature = "alloc")]
+pub mod thin_box;
+#[cfg(feature = "alloc")]
 pub mod vec;
+#[cfg(feature = "alloc")]
+pub mod vec_deque;
+#[cfg(feature = "zeroize")]
+pub mod zeroizing;
 
 pub use crate::mut_ref::AliasableMut;
 
 /// Export of all types enabled.
 pub mod prelude {
+    #[cfg(feature = "alloc")]
+    pub use crate::arena::*;
+    pub use crate::array_string::*;
+    pub use crate::array_vec::*;
     #[cfg(feature = "alloc")]
     pub use crate::boxed::*;
     #[cfg(feature = "alloc")]
+    pub use crate::btree_map::{AliasableBTreeMap, UniqueBTreeMap};
+    pub use crate::cell::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::cow::*;
+    pub use crate::cursor::*;
+    #[cfg(feature = "std")]
+    pub use crate::ffi::*;
+    #[cfg(all(feature = "alloc", feature = "hashbrown"))]
+    pub use crate::hash_map::{AliasableHashMap, UniqueHashMap};
+    #[cfg(feature = "alloc")]
+    pub use crate::header_vec::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::intrusive_list::{IntrusiveList, ListCursorMut, ListHandle, ListNode};
+    pub use crate::io_buf::*;
+    #[cfg(all(feature = "stable_deref_trait", feature = "aliasable_deref_trait"))]
+    pub use crate::owning_ref::*;
+    #[cfg(feature = "std")]
+    pub use crate::path::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::small_vec::AliasableSmallVec;
+    #[cfg(feature = "alloc")]
     pub use crate::string::*;
     #[cfg(feature = "alloc")]
+    pub use crate::thin_box::*;
+    #[cfg(feature = "alloc")]
     pub use crate::vec::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::vec_deque::*;
+    #[cfg(feature = "zeroize")]
+    pub use crate::zeroizing::*;
 
     pub use crate::mut_ref::*;
 }
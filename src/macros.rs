@@ -0,0 +1,84 @@
+//! Internal macros implementing comparisons across container types.
+
+/// Implements `PartialEq` in both directions between two slice-like types
+/// with element types `T` and `U`, by comparing them as slices.
+#[cfg(feature = "alloc")]
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($vars)* T, U> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+
+        impl<$($vars)* T, U> PartialEq<$lhs> for $rhs
+        where
+            U: PartialEq<T>,
+        {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+    };
+}
+
+/// Implements `PartialOrd` in both directions between two slice-like types
+/// with the same element type `T`, by comparing them as slices.
+#[cfg(feature = "alloc")]
+macro_rules! impl_slice_ord {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($vars)* T: PartialOrd> PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                self[..].partial_cmp(&other[..])
+            }
+        }
+
+        impl<$($vars)* T: PartialOrd> PartialOrd<$lhs> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                self[..].partial_cmp(&other[..])
+            }
+        }
+    };
+}
+
+/// Implements `PartialEq` and `PartialOrd` in both directions between two
+/// string types, by comparing them as `str`s.
+#[cfg(feature = "alloc")]
+macro_rules! impl_str_cmp {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($vars)*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+
+        impl<$($vars)*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+
+        impl<$($vars)*> PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                self[..].partial_cmp(&other[..])
+            }
+        }
+
+        impl<$($vars)*> PartialOrd<$lhs> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                self[..].partial_cmp(&other[..])
+            }
+        }
+    };
+}
@@ -1,5 +1,6 @@
 //! Aliasable `&mut`.
 
+use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
@@ -8,6 +9,8 @@ use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use core::ptr::NonNull;
 
+use crate::cell::AliasableCell;
+
 /// Basic aliasable alternative to `&mut`.
 ///
 /// Note that this does not circumvent the core aliasing rules of Rust; if you use this to create
@@ -16,7 +19,7 @@ use core::ptr::NonNull;
 ///
 /// - You may hold any number of `AliasableMut`s and no references to a location.
 /// - You may hold any number of `AliasableMut`s and any number of shared references to a location
-/// at once.
+///   at once.
 /// - You may hold any number of `AliasableMut`s and one mutable reference to a location at once.
 #[repr(transparent)]
 pub struct AliasableMut<'a, T: ?Sized> {
@@ -28,9 +31,10 @@ pub struct AliasableMut<'a, T: ?Sized> {
 impl<'a, T: ?Sized> AliasableMut<'a, T> {
     /// Construct an `AliasableMut` from an `&mut`.
     #[inline]
-    pub fn from_unique(ptr: &'a mut T) -> Self {
+    pub const fn from_unique(ptr: &'a mut T) -> Self {
         Self {
-            inner: NonNull::from(ptr),
+            // SAFETY: References are never null.
+            inner: unsafe { NonNull::new_unchecked(ptr) },
             _lifetime: PhantomData,
         }
     }
@@ -63,6 +67,18 @@ impl<'a, T: ?Sized> AliasableMut<'a, T> {
     }
 }
 
+impl<T> AliasableMut<'_, T> {
+    /// Returns a copyable [`AliasableCell`] handle to the location, which keeps
+    /// `self` borrowed for as long as any copy of it is in use.
+    #[inline]
+    pub fn as_cell(&mut self) -> AliasableCell<'_, T> {
+        // SAFETY: The location is borrowed mutably for the lifetime of `self`.
+        // The cell is built from the raw pointer rather than a reborrow, so
+        // it doesn't invalidate other `AliasableMut`s to the location.
+        unsafe { AliasableCell::from_raw(self.inner) }
+    }
+}
+
 impl<'a, T: ?Sized> From<&'a mut T> for AliasableMut<'a, T> {
     fn from(ptr: &'a mut T) -> Self {
         Self::from_unique(ptr)
@@ -103,6 +119,20 @@ impl<T: ?Sized> AsMut<T> for AliasableMut<'_, T> {
     }
 }
 
+impl<T: ?Sized> Borrow<T> for AliasableMut<'_, T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for AliasableMut<'_, T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
 impl<T: ?Sized> fmt::Debug for AliasableMut<'_, T>
 where
     T: fmt::Debug,
@@ -112,6 +142,21 @@ where
     }
 }
 
+impl<T: ?Sized> fmt::Display for AliasableMut<'_, T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> fmt::Pointer for AliasableMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, f)
+    }
+}
+
 unsafe impl<T: ?Sized> Send for AliasableMut<'_, T> where T: Send {}
 unsafe impl<T: ?Sized> Sync for AliasableMut<'_, T> where T: Sync {}
 
@@ -147,6 +192,20 @@ impl<T: PartialOrd + ?Sized> PartialOrd for AliasableMut<'_, T> {
     }
 }
 
+impl<T: PartialEq + ?Sized> PartialEq<&mut T> for AliasableMut<'_, T> {
+    #[inline]
+    fn eq(&self, other: &&mut T) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: PartialOrd + ?Sized> PartialOrd<&mut T> for AliasableMut<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &&mut T) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
 impl<T: Ord + ?Sized> Ord for AliasableMut<'_, T> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -165,3 +224,21 @@ unsafe impl<T: ?Sized> crate::StableDeref for AliasableMut<'_, T> {}
 
 #[cfg(feature = "aliasable_deref_trait")]
 unsafe impl<T: ?Sized> crate::AliasableDeref for AliasableMut<'_, T> {}
+
+#[cfg(feature = "unsize")]
+unsafe impl<'a, T, U: ?Sized + 'a> unsize::CoerciblePtr<U> for AliasableMut<'a, T> {
+    type Pointee = T;
+    type Output = AliasableMut<'a, U>;
+
+    fn as_sized_ptr(&mut self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    unsafe fn replace_ptr(self, new: *mut U) -> AliasableMut<'a, U> {
+        // Replace the inner pointer type, keeping the same lifetime.
+        AliasableMut {
+            inner: self.inner.replace_ptr(new),
+            _lifetime: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! References carried along with the owner of the data they point into.
+//!
+//! This is a replacement for the `owning_ref` crate, restricted to owners
+//! which are both [`StableDeref`] and [`AliasableDeref`]. The first ensures
+//! the data stays put as the owner moves, and the second ensures moving the
+//! owner does not assert unique access to the data, which would invalidate the
+//! reference.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+use crate::{AliasableDeref, StableDeref};
+
+/// A shared reference to a `T` carried along with the owner `O` of the data it
+/// points into.
+pub struct OwningRef<O, T: ?Sized> {
+    owner: O,
+    reference: NonNull<T>,
+    // We hold a `&T`.
+    _marker: PhantomData<*const T>,
+}
+
+impl<O, T: ?Sized> OwningRef<O, T>
+where
+    O: StableDeref<Target = T> + AliasableDeref,
+{
+    /// Constructs an `OwningRef` to the target of `owner`.
+    pub fn new(owner: O) -> Self {
+        let reference = NonNull::from(&*owner);
+        Self {
+            owner,
+            reference,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O, T: ?Sized> OwningRef<O, T>
+where
+    O: StableDeref + AliasableDeref,
+{
+    /// Narrows the reference to something reachable from it.
+    pub fn map<F, U: ?Sized>(self, f: F) -> OwningRef<O, U>
+    where
+        F: for<'a> FnOnce(&'a T) -> &'a U,
+    {
+        let reference = NonNull::from(f(&self));
+        OwningRef {
+            owner: self.owner,
+            reference,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Attempts to narrow the reference to something reachable from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`, dropping the owner.
+    pub fn try_map<F, U: ?Sized, E>(self, f: F) -> Result<OwningRef<O, U>, E>
+    where
+        F: for<'a> FnOnce(&'a T) -> Result<&'a U, E>,
+    {
+        let reference = NonNull::from(f(&self)?);
+        Ok(OwningRef {
+            owner: self.owner,
+            reference,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Narrows the reference to something reachable from it or from the
+    /// target of the owner.
+    ///
+    /// `f` is only given the target of the owner and never the owner itself,
+    /// as a reference into the owner would dangle once it is moved.
+    pub fn map_with_owner<F, U: ?Sized>(self, f: F) -> OwningRef<O, U>
+    where
+        F: for<'a> FnOnce(&'a O::Target, &'a T) -> &'a U,
+    {
+        let reference = NonNull::from(f(&*self.owner, &self));
+        OwningRef {
+            owner: self.owner,
+            reference,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the owner.
+    pub fn as_owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Discards the reference and returns the owner.
+    pub fn into_owner(self) -> O {
+        self.owner
+    }
+}
+
+impl<O, T: ?Sized> Deref for OwningRef<O, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The reference points into the data of the owner, which is
+        // kept alive and in place, and only shared access is handed out.
+        unsafe { self.reference.as_ref() }
+    }
+}
+
+impl<O, T: ?Sized> AsRef<T> for OwningRef<O, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<O, T: ?Sized> fmt::Debug for OwningRef<O, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+// SAFETY: Sending the owner along with a `&T` into it.
+unsafe impl<O: Send, T: ?Sized + Sync> Send for OwningRef<O, T> {}
+// SAFETY: Sharing the owner along with a `&T` into it.
+unsafe impl<O: Sync, T: ?Sized + Sync> Sync for OwningRef<O, T> {}
+
+/// A mutable reference to a `T` carried along with the owner `O` of the data
+/// it points into.
+///
+/// Unlike [`OwningRef`], the owner can't be accessed while the reference is
+/// held, as it could be used to reach the referenced data. For the same
+/// reason there is deliberately no `map_with_owner`, as the target of the
+/// owner would alias the mutable reference.
+pub struct OwningRefMut<O, T: ?Sized> {
+    owner: O,
+    reference: NonNull<T>,
+    // We hold a `&mut T`.
+    _marker: PhantomData<*mut T>,
+}
+
+impl<O, T: ?Sized> OwningRefMut<O, T>
+where
+    O: StableDeref<Target = T> + AliasableDeref + DerefMut,
+{
+    /// Constructs an `OwningRefMut` to the target of `owner`.
+    pub fn new(mut owner: O) -> Self {
+        let reference = NonNull::from(&mut *owner);
+        Self {
+            owner,
+            reference,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O, T: ?Sized> OwningRefMut<O, T>
+where
+    O: StableDeref + AliasableDeref + DerefMut,
+{
+    /// Narrows the reference to something reachable from it.
+    pub fn map<F, U: ?Sized>(mut self, f: F) -> OwningRefMut<O, U>
+    where
+        F: for<'a> FnOnce(&'a mut T) -> &'a mut U,
+    {
+        let reference = NonNull::from(f(&mut self));
+        OwningRefMut {
+            owner: self.owner,
+            reference,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Attempts to narrow the reference to something reachable from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`, dropping the owner.
+    pub fn try_map<F, U: ?Sized, E>(mut self, f: F) -> Result<OwningRefMut<O, U>, E>
+    where
+        F: for<'a> FnOnce(&'a mut T) -> Result<&'a mut U, E>,
+    {
+        let reference = NonNull::from(f(&mut self)?);
+        Ok(OwningRefMut {
+            owner: self.owner,
+            reference,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Downgrades to a shared [`OwningRef`].
+    pub fn into_ref(self) -> OwningRef<O, T> {
+        OwningRef {
+            owner: self.owner,
+            reference: self.reference,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Discards the reference and returns the owner.
+    pub fn into_owner(self) -> O {
+        self.owner
+    }
+}
+
+impl<O, T: ?Sized> Deref for OwningRefMut<O, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The reference points into the data of the owner, which is
+        // kept alive and in place, and is only reachable through `self`.
+        unsafe { self.reference.as_ref() }
+    }
+}
+
+impl<O, T: ?Sized> DerefMut for OwningRefMut<O, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The reference points into the data of the owner, which is
+        // kept alive and in place, and is only reachable through `self`.
+        unsafe { self.reference.as_mut() }
+    }
+}
+
+impl<O, T: ?Sized> AsRef<T> for OwningRefMut<O, T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<O, T: ?Sized> AsMut<T> for OwningRefMut<O, T> {
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<O, T: ?Sized> fmt::Debug for OwningRefMut<O, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+// SAFETY: Sending the owner along with a `&mut T` into it.
+unsafe impl<O: Send, T: ?Sized + Send> Send for OwningRefMut<O, T> {}
+// SAFETY: Sharing the owner along with a `&mut T` into it, which only hands
+// out a `&T` when shared.
+unsafe impl<O: Sync, T: ?Sized + Sync> Sync for OwningRefMut<O, T> {}
@@ -0,0 +1,160 @@
+//! Aliasable `PathBuf`.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::pin::Pin;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use crate::ffi::AliasableOsString;
+
+pub use std::path::PathBuf as UniquePathBuf;
+
+/// Basic aliasable (non `core::ptr::Unique`) alternative to
+/// [`std::path::PathBuf`].
+pub struct AliasablePathBuf(AliasableOsString);
+
+impl AliasablePathBuf {
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        self
+    }
+
+    /// Consumes `self` into an [`AliasableOsString`].
+    pub fn into_os_string(self) -> AliasableOsString {
+        self.0
+    }
+
+    /// Construct an `AliasablePathBuf` from a [`UniquePathBuf`].
+    pub fn from_unique(p: UniquePathBuf) -> Self {
+        Self(p.into_os_string().into())
+    }
+
+    /// Consumes `self` and converts it into a non-aliasable [`UniquePathBuf`].
+    #[inline]
+    pub fn into_unique(p: AliasablePathBuf) -> UniquePathBuf {
+        UniquePathBuf::from(AliasableOsString::into_unique(p.into_os_string()))
+    }
+
+    /// Convert a pinned [`AliasablePathBuf`] to a `core::ptr::Unique` backed
+    /// pinned [`UniquePathBuf`].
+    pub fn into_unique_pin(pin: Pin<AliasablePathBuf>) -> Pin<UniquePathBuf> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let aliasable = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasablePathBuf::into_unique(aliasable))
+        }
+    }
+
+    /// Convert a pinned `core::ptr::Unique` backed [`UniquePathBuf`] to a
+    /// pinned [`AliasablePathBuf`].
+    pub fn from_unique_pin(pin: Pin<UniquePathBuf>) -> Pin<AliasablePathBuf> {
+        // SAFETY: The pointer is not changed, just the container.
+        unsafe {
+            let unique = Pin::into_inner_unchecked(pin);
+            Pin::new_unchecked(AliasablePathBuf::from(unique))
+        }
+    }
+}
+
+impl From<UniquePathBuf> for AliasablePathBuf {
+    #[inline]
+    fn from(p: UniquePathBuf) -> Self {
+        Self::from_unique(p)
+    }
+}
+
+impl From<AliasablePathBuf> for UniquePathBuf {
+    #[inline]
+    fn from(p: AliasablePathBuf) -> Self {
+        AliasablePathBuf::into_unique(p)
+    }
+}
+
+impl Deref for AliasablePathBuf {
+    type Target = Path;
+
+    #[inline]
+    fn deref(&self) -> &Path {
+        Path::new(&*self.0)
+    }
+}
+
+impl AsRef<Path> for AliasablePathBuf {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        self
+    }
+}
+
+impl AsRef<OsStr> for AliasablePathBuf {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AliasablePathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), f)
+    }
+}
+
+impl Default for AliasablePathBuf {
+    #[inline]
+    fn default() -> Self {
+        Self::from_unique(UniquePathBuf::default())
+    }
+}
+
+impl Clone for AliasablePathBuf {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+// Paths are compared, ordered and hashed by their components and not by their
+// bytes, so none of these can be derived.
+impl PartialEq for AliasablePathBuf {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for AliasablePathBuf {}
+
+impl PartialOrd for AliasablePathBuf {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AliasablePathBuf {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl Hash for AliasablePathBuf {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        (**self).hash(hasher);
+    }
+}
+
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl crate::StableDeref for AliasablePathBuf {}
+
+#[cfg(feature = "aliasable_deref_trait")]
+unsafe impl crate::AliasableDeref for AliasablePathBuf {}
@@ -0,0 +1,464 @@
+//! Aliasable vector storing a few elements inline.
+
+use core::cell::UnsafeCell;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::marker::PhantomPinned;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::{cmp, fmt, slice};
+
+use crate::vec::AliasableVec;
+
+/// An array type which can be used as the inline storage of an
+/// [`AliasableSmallVec`].
+///
+/// # Safety
+///
+/// The type must have the layout of `[Self::Item; Self::CAPACITY]`.
+pub unsafe trait Array {
+    /// The type of the elements.
+    type Item;
+
+    /// The number of elements the array holds.
+    const CAPACITY: usize;
+}
+
+unsafe impl<T, const N: usize> Array for [T; N] {
+    type Item = T;
+    const CAPACITY: usize = N;
+}
+
+/// Aliasable (non `core::ptr::Unique`) vector which stores up to
+/// `A::CAPACITY` elements inline and spills to an [`AliasableVec`] on the heap
+/// beyond that.
+///
+/// Inline elements live inside the `AliasableSmallVec` itself, so their
+/// addresses change whenever it is moved. The type is deliberately `!Unpin`,
+/// and the inline storage is an `UnsafeCell`. The compiler and Miri currently
+/// don't treat an `&mut` to a `!Unpin` type as asserting unique access, so
+/// while the vec stays in place, pointers to inline elements stay valid across
+/// calls to `&mut self` methods such as `push` and `pop`. This relies on the
+/// current semantics of `!Unpin` rather than on a language guarantee, unlike
+/// the stability of spilled elements.
+///
+/// Once the vec has spilled, see [`AliasableSmallVec::spilled`], its elements
+/// live in a heap buffer whose address is unaffected by moves, and only
+/// changes when the buffer is reallocated to grow past its capacity. A spilled
+/// vec never moves its elements back inline, and [`AliasableSmallVec::spill`]
+/// can be used to move them to the heap up front.
+///
+/// Vecs of zero-sized types never allocate, and so never spill.
+pub struct AliasableSmallVec<A: Array> {
+    data: Data<A>,
+}
+
+enum Data<A: Array> {
+    Inline { len: usize, buf: InlineBuf<A> },
+    Heap(AliasableVec<A::Item>),
+}
+
+struct InlineBuf<A> {
+    buf: UnsafeCell<MaybeUninit<A>>,
+    // `&mut` to a `!Unpin` type currently doesn't assert unique access.
+    _pinned: PhantomPinned,
+}
+
+impl<A: Array> InlineBuf<A> {
+    const fn new() -> Self {
+        Self {
+            buf: UnsafeCell::new(MaybeUninit::uninit()),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    fn as_ptr(&self) -> *mut A::Item {
+        self.buf.get().cast()
+    }
+}
+
+impl<A: Array> AliasableSmallVec<A> {
+    /// The number of elements that can be stored inline, which is unlimited
+    /// for zero-sized types.
+    pub const INLINE_CAPACITY: usize = if size_of::<A::Item>() == 0 {
+        usize::MAX
+    } else {
+        A::CAPACITY
+    };
+
+    /// Constructs a new, empty `AliasableSmallVec` with its elements inline.
+    pub const fn new() -> Self {
+        Self {
+            data: Data::Inline {
+                len: 0,
+                buf: InlineBuf::new(),
+            },
+        }
+    }
+
+    /// Constructs a new, empty `AliasableSmallVec` which can hold at least
+    /// `capacity` elements without reallocating, spilling to the heap up front
+    /// if they don't fit inline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity in bytes overflows `isize::MAX`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= Self::INLINE_CAPACITY {
+            Self::new()
+        } else {
+            Self::from_vec(AliasableVec::with_capacity(capacity))
+        }
+    }
+
+    /// Construct a spilled `AliasableSmallVec` from an [`AliasableVec`],
+    /// keeping its heap buffer, and so the addresses of its elements, as is.
+    pub fn from_vec(vec: AliasableVec<A::Item>) -> Self {
+        if size_of::<A::Item>() == 0 {
+            // Zero-sized elements are moved inline without copying anything,
+            // and the vec has no allocation to free.
+            let (_, len, _) = vec.into_raw_parts();
+            return Self {
+                data: Data::Inline {
+                    len,
+                    buf: InlineBuf::new(),
+                },
+            };
+        }
+        Self {
+            data: Data::Heap(vec),
+        }
+    }
+
+    /// Consumes `self` into an [`AliasableVec`], which only allocates if the
+    /// elements are inline.
+    pub fn into_vec(mut self) -> AliasableVec<A::Item> {
+        if let Some(vec) = self.take_inline(0) {
+            return vec;
+        }
+        let empty = Data::Inline {
+            len: 0,
+            buf: InlineBuf::new(),
+        };
+        match mem::replace(&mut self.data, empty) {
+            Data::Heap(vec) => vec,
+            Data::Inline { .. } => unreachable!(),
+        }
+    }
+
+    /// Returns the number of elements in the vec.
+    pub fn len(&self) -> usize {
+        match &self.data {
+            Data::Inline { len, .. } => *len,
+            Data::Heap(vec) => vec.len(),
+        }
+    }
+
+    /// Returns the number of elements the vec can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        match &self.data {
+            Data::Inline { .. } => Self::INLINE_CAPACITY,
+            Data::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Returns `true` if the vec contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the elements are stored on the heap, and so keep
+    /// their addresses when the vec is moved.
+    pub fn spilled(&self) -> bool {
+        matches!(self.data, Data::Heap(_))
+    }
+
+    /// Moves the elements to the heap if they are inline, after which their
+    /// addresses are unaffected by moves of the vec.
+    ///
+    /// This does nothing for zero-sized types, which have no addresses to
+    /// preserve.
+    pub fn spill(&mut self) {
+        self.spill_to(self.len());
+    }
+
+    /// Returns a raw pointer to the first element, which is only stable
+    /// across moves of the vec if it is [spilled](Self::spilled).
+    pub fn as_ptr(&self) -> *const A::Item {
+        match &self.data {
+            Data::Inline { buf, .. } => buf.as_ptr().cast_const(),
+            Data::Heap(vec) => vec.as_ptr(),
+        }
+    }
+
+    /// Returns a raw mutable pointer to the first element, which is only
+    /// stable across moves of the vec if it is [spilled](Self::spilled).
+    pub fn as_mut_ptr(&mut self) -> *mut A::Item {
+        match &mut self.data {
+            Data::Inline { buf, .. } => buf.as_ptr(),
+            Data::Heap(vec) => vec.as_mut_ptr(),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// This spills the elements to the heap if they no longer fit inline, and
+    /// may reallocate a spilled vec, moving its elements either way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.data {
+            Data::Inline { len, .. } => {
+                let required = len.checked_add(additional).expect("capacity overflow");
+                if required > Self::INLINE_CAPACITY {
+                    let doubled = Self::INLINE_CAPACITY.saturating_mul(2);
+                    self.spill_to(cmp::max(required, doubled));
+                }
+            }
+            Data::Heap(vec) => vec.reserve(additional),
+        }
+    }
+
+    /// Appends an element to the back of the vec, spilling it to the heap if
+    /// the inline storage is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn push(&mut self, value: A::Item) {
+        self.reserve(1);
+        match &mut self.data {
+            Data::Inline { len, buf } => {
+                // SAFETY: There is spare inline capacity, so the slot after the
+                // last element is uninitialized.
+                unsafe { buf.as_ptr().add(*len).write(value) };
+                *len += 1;
+            }
+            Data::Heap(vec) => vec.push(value),
+        }
+    }
+
+    /// Removes the last element of the vec and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<A::Item> {
+        match &mut self.data {
+            Data::Inline { len, buf } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                // SAFETY: The slot was initialized and is no longer tracked as
+                // such.
+                Some(unsafe { buf.as_ptr().add(*len).read() })
+            }
+            Data::Heap(vec) => vec.pop(),
+        }
+    }
+
+    /// Shortens the vec, keeping the first `len` elements and dropping the
+    /// rest.
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.data {
+            Data::Inline { len, buf } => {
+                if new_len >= *len {
+                    return;
+                }
+                let tail = ptr::slice_from_raw_parts_mut(
+                    // SAFETY: `new_len` is within the initialized elements.
+                    unsafe { buf.as_ptr().add(new_len) },
+                    *len - new_len,
+                );
+                // Update the length first, so a panicking drop never leaves it
+                // covering a dropped element.
+                *len = new_len;
+                // SAFETY: The elements were initialized and are no longer
+                // tracked.
+                unsafe { ptr::drop_in_place(tail) };
+            }
+            Data::Heap(vec) => vec.truncate(new_len),
+        }
+    }
+
+    /// Drops all the elements of the vec, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Moves inline elements to a heap buffer which can hold at least
+    /// `capacity` elements.
+    fn spill_to(&mut self, capacity: usize) {
+        if size_of::<A::Item>() == 0 {
+            return;
+        }
+        if let Some(vec) = self.take_inline(capacity) {
+            // The inline storage is empty, so replacing it drops nothing.
+            self.data = Data::Heap(vec);
+        }
+    }
+
+    /// Moves any inline elements into a new [`AliasableVec`] which can hold
+    /// at least `capacity` elements, leaving the inline storage empty.
+    fn take_inline(&mut self, capacity: usize) -> Option<AliasableVec<A::Item>> {
+        let (len, buf) = match &mut self.data {
+            Data::Inline { len, buf } => (len, buf),
+            Data::Heap(_) => return None,
+        };
+        let (dst, _, cap) = AliasableVec::with_capacity(cmp::max(capacity, *len)).into_raw_parts();
+        // SAFETY: The new buffer has room for the inline elements, which are
+        // moved over as is and no longer tracked inline.
+        let vec = unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), dst.as_ptr(), *len);
+            AliasableVec::from_raw_parts(dst, *len, cap)
+        };
+        *len = 0;
+        Some(vec)
+    }
+}
+
+// SAFETY: The inline storage is only mutated through `&mut self`.
+unsafe impl<A: Array + Sync> Sync for AliasableSmallVec<A> where A::Item: Sync {}
+
+impl<A: Array> Drop for AliasableSmallVec<A> {
+    fn drop(&mut self) {
+        // Spilled elements are dropped along with their `AliasableVec`.
+        if !self.spilled() {
+            self.clear();
+        }
+    }
+}
+
+impl<A: Array> From<AliasableVec<A::Item>> for AliasableSmallVec<A> {
+    #[inline]
+    fn from(vec: AliasableVec<A::Item>) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
+impl<A: Array> From<AliasableSmallVec<A>> for AliasableVec<A::Item> {
+    #[inline]
+    fn from(small: AliasableSmallVec<A>) -> Self {
+        small.into_vec()
+    }
+}
+
+impl<A: Array> Deref for AliasableSmallVec<A> {
+    type Target = [A::Item];
+
+    #[inline]
+    fn deref(&self) -> &[A::Item] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+}
+
+impl<A: Array> DerefMut for AliasableSmallVec<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [A::Item] {
+        let len = self.len();
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), len) }
+    }
+}
+
+impl<A: Array> AsRef<[A::Item]> for AliasableSmallVec<A> {
+    fn as_ref(&self) -> &[A::Item] {
+        self
+    }
+}
+
+impl<A: Array> AsMut<[A::Item]> for AliasableSmallVec<A> {
+    fn as_mut(&mut self) -> &mut [A::Item] {
+        self
+    }
+}
+
+impl<A: Array> Extend<A::Item> for AliasableSmallVec<A> {
+    fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<A: Array> FromIterator<A::Item> for AliasableSmallVec<A> {
+    fn from_iter<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<A: Array> fmt::Debug for AliasableSmallVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<A: Array> Default for AliasableSmallVec<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Array> Clone for AliasableSmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut clone = Self::with_capacity(self.len());
+        clone.extend(self.iter().cloned());
+        clone
+    }
+}
+
+impl<A: Array> PartialEq for AliasableSmallVec<A>
+where
+    A::Item: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<A: Array> Eq for AliasableSmallVec<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for AliasableSmallVec<A>
+where
+    A::Item: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<A: Array> Ord for AliasableSmallVec<A>
+where
+    A::Item: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<A: Array> Hash for AliasableSmallVec<A>
+where
+    A::Item: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
@@ -1,10 +1,15 @@
 //! Aliasable `String`.
 
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::Infallible;
 use core::hash::{Hash, Hasher};
-use core::ops::{Deref, DerefMut};
+use core::ops::{Add, AddAssign, Deref, DerefMut, Index, IndexMut};
 use core::pin::Pin;
+use core::slice::SliceIndex;
 use core::{fmt, str};
 
+use crate::boxed::AliasableBox;
 use crate::vec::AliasableVec;
 
 pub use alloc::string::String as UniqueString;
@@ -15,6 +20,11 @@ pub use alloc::string::String as UniqueString;
 pub struct AliasableString(AliasableVec<u8>);
 
 impl AliasableString {
+    /// Constructs a new, empty `AliasableString` without allocating.
+    pub const fn new() -> Self {
+        Self(AliasableVec::new())
+    }
+
     /// Consumes `self` into an [`AliasableVec`] of UTF-8 bytes.
     pub fn into_bytes(self) -> AliasableVec<u8> {
         self.0
@@ -68,13 +78,59 @@ impl From<AliasableString> for UniqueString {
     }
 }
 
+impl From<&str> for AliasableString {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self(AliasableVec::from(s))
+    }
+}
+
+impl From<&mut str> for AliasableString {
+    #[inline]
+    fn from(s: &mut str) -> Self {
+        Self::from(&*s)
+    }
+}
+
+impl From<char> for AliasableString {
+    #[inline]
+    fn from(c: char) -> Self {
+        Self::from(c.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl From<AliasableBox<str>> for AliasableString {
+    #[inline]
+    fn from(aliasable: AliasableBox<str>) -> Self {
+        Self::from_unique(AliasableBox::into_unique(aliasable).into_string())
+    }
+}
+
+impl From<AliasableString> for AliasableBox<str> {
+    /// Converts the string into a boxed `str`, which reallocates if the string
+    /// has excess capacity.
+    #[inline]
+    fn from(s: AliasableString) -> Self {
+        AliasableBox::from_unique(AliasableString::into_unique(s).into_boxed_str())
+    }
+}
+
+impl str::FromStr for AliasableString {
+    type Err = Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
 impl Deref for AliasableString {
     type Target = str;
 
     #[inline]
     fn deref(&self) -> &str {
         // SAFETY: `AliasableString` will only ever contain UTF-8.
-        unsafe { str::from_utf8_unchecked(&*self.0) }
+        unsafe { str::from_utf8_unchecked(&self.0) }
     }
 }
 
@@ -82,14 +138,57 @@ impl DerefMut for AliasableString {
     #[inline]
     fn deref_mut(&mut self) -> &mut str {
         // SAFETY: `AliasableString` will only ever contain UTF-8.
-        unsafe { str::from_utf8_unchecked_mut(&mut *self.0) }
+        unsafe { str::from_utf8_unchecked_mut(&mut self.0) }
+    }
+}
+
+impl<I: SliceIndex<str>> Index<I> for AliasableString {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        (**self).index(index)
+    }
+}
+
+impl<I: SliceIndex<str>> IndexMut<I> for AliasableString {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        (**self).index_mut(index)
+    }
+}
+
+impl Add<&str> for AliasableString {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &str) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign<&str> for AliasableString {
+    #[inline]
+    fn add_assign(&mut self, other: &str) {
+        // Appending a `str` keeps the contents valid UTF-8.
+        self.0
+            .with_unique(|vec| vec.extend_from_slice(other.as_bytes()));
+    }
+}
+
+impl fmt::Write for AliasableString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        *self += s;
+        Ok(())
     }
 }
 
 impl AsRef<str> for AliasableString {
     #[inline]
     fn as_ref(&self) -> &str {
-        &*self
+        self
     }
 }
 
@@ -99,16 +198,36 @@ impl AsMut<str> for AliasableString {
     }
 }
 
+impl Borrow<str> for AliasableString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl BorrowMut<str> for AliasableString {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
 impl fmt::Debug for AliasableString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(self.as_ref(), f)
     }
 }
 
+impl fmt::Display for AliasableString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), f)
+    }
+}
+
 impl Default for AliasableString {
     #[inline]
     fn default() -> Self {
-        Self::from_unique(UniqueString::default())
+        Self::new()
     }
 }
 
@@ -124,7 +243,7 @@ impl Clone for AliasableString {
 }
 
 // Deriving `Hash` would be incorrect because it would hash as bytes and not a string.
-#[allow(clippy::derive_hash_xor_eq)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 impl Hash for AliasableString {
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
@@ -132,8 +251,116 @@ impl Hash for AliasableString {
     }
 }
 
+impl_str_cmp!([] AliasableString, str);
+impl_str_cmp!(['a] AliasableString, &'a str);
+impl_str_cmp!([] AliasableString, UniqueString);
+
 #[cfg(feature = "stable_deref_trait")]
 unsafe impl crate::StableDeref for AliasableString {}
 
 #[cfg(feature = "aliasable_deref_trait")]
 unsafe impl crate::AliasableDeref for AliasableString {}
+
+unsafe impl crate::io_buf::IoBuf for AliasableString {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        crate::io_buf::IoBuf::as_ptr(&self.0)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AliasableString {
+    /// Zeroizes the entire capacity of the string, leaving it empty.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AliasableString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueString::arbitrary(u).map(Self::from_unique)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueString::arbitrary_take_rest(u).map(Self::from_unique)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UniqueString::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for AliasableString {
+    type Parameters = <UniqueString as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = proptest::strategy::Map<
+        proptest::arbitrary::StrategyFor<UniqueString>,
+        fn(UniqueString) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::arbitrary::any_with::<UniqueString>(args),
+            Self::from_unique,
+        )
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for AliasableString {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        (**self).serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for AliasableString {
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        UniqueString::deserialize_reader(reader).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl bincode::Encode for AliasableString {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        (**self).encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for AliasableString {
+    #[inline]
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueString::decode(decoder).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context> bincode::BorrowDecode<'de, Context> for AliasableString {
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueString::borrow_decode(decoder).map(Self::from_unique)
+    }
+}
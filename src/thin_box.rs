@@ -0,0 +1,276 @@
+//! Aliasable thin `Box`.
+
+use core::alloc::Layout;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+
+use crate::vec::UniqueVec;
+
+/// Word stored right before the value, holding the metadata of a wide pointer
+/// to it. Wide pointers are laid out as their address followed by their
+/// metadata.
+type Header = MaybeUninit<*const ()>;
+
+/// Aliasable (non `core::ptr::Unique`) box that is a single pointer wide even
+/// when `T` is unsized.
+///
+/// The pointer metadata, such as a slice length or a vtable, is stored in a
+/// header right before the value in the allocation, and the box points to the
+/// value itself. Sized values have no metadata, but still reserve the header
+/// so they can be unsized in place. Unsized values are constructed from
+/// [`UniqueVec`]s, or by unsizing an `AliasableThinBox<T>` with the `unsize`
+/// feature.
+pub struct AliasableThinBox<T: ?Sized> {
+    ptr: NonNull<u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> AliasableThinBox<T> {
+    /// Allocates memory and moves `value` into it.
+    pub fn new(value: T) -> Self {
+        let (layout, offset) = layout_with_header(Layout::new::<T>());
+        // SAFETY: `offset` is within the allocation and aligned for `T`.
+        unsafe {
+            let base = allocate(layout);
+            let value_ptr = base.as_ptr().add(offset).cast::<T>();
+            value_ptr.write(value);
+            Self::from_parts(value_ptr)
+        }
+    }
+}
+
+impl<T: ?Sized> AliasableThinBox<T> {
+    /// Whether pointers to `T` carry metadata, in a second word.
+    const WIDE: bool = {
+        let size = size_of::<*mut T>();
+        assert!(
+            size == size_of::<Header>() || size == 2 * size_of::<Header>(),
+            "unsupported pointer metadata"
+        );
+        size != size_of::<Header>()
+    };
+
+    /// Returns a raw pointer to the value.
+    pub fn as_ptr(&self) -> *const T {
+        self.value_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the value.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.value_ptr()
+    }
+
+    /// Writes the metadata of `value` into the header before it.
+    ///
+    /// # Safety
+    ///
+    /// `value` must point to an initialized value at the offset returned by
+    /// [`layout_with_header`] for its layout, within an allocation made with
+    /// that layout.
+    unsafe fn from_parts(value: *mut T) -> Self {
+        let ptr = value.cast::<u8>();
+        if Self::WIDE {
+            let words = ptr::addr_of!(value).cast::<Header>();
+            header(ptr).write(words.add(1).read());
+        }
+        Self {
+            // SAFETY: The value is within an allocation, so it is not null.
+            ptr: NonNull::new_unchecked(ptr),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn value_ptr(&self) -> *mut T {
+        let mut value = MaybeUninit::<*mut T>::uninit();
+        let words = value.as_mut_ptr().cast::<Header>();
+        // SAFETY: A `*mut T` is made of its address, followed by the metadata
+        // in the header if it is wide.
+        unsafe {
+            words.write(MaybeUninit::new(self.ptr.as_ptr().cast_const().cast()));
+            if Self::WIDE {
+                words.add(1).write(header(self.ptr.as_ptr()).read());
+            }
+            value.assume_init()
+        }
+    }
+}
+
+impl<T> From<UniqueVec<T>> for AliasableThinBox<[T]> {
+    fn from(mut unique: UniqueVec<T>) -> Self {
+        let len = unique.len();
+        let array = Layout::array::<T>(len).expect("capacity overflow");
+        let (layout, offset) = layout_with_header(array);
+        // SAFETY: `offset` is within the allocation and aligned for `T`, and the
+        // elements are moved out of the vec which no longer tracks them.
+        unsafe {
+            let base = allocate(layout);
+            let data = base.as_ptr().add(offset).cast::<T>();
+            ptr::copy_nonoverlapping(unique.as_ptr(), data, len);
+            unique.set_len(0);
+            Self::from_parts(ptr::slice_from_raw_parts_mut(data, len))
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for AliasableThinBox<T> {
+    fn drop(&mut self) {
+        let value = self.value_ptr();
+        // SAFETY: As `self` is being dropped we can safely assume any aliasing
+        // has ended. The layout and offset are recomputed from the value the
+        // same way it was allocated.
+        unsafe {
+            let (layout, offset) = layout_with_header(Layout::for_value(&*value));
+            ptr::drop_in_place(value);
+            dealloc(self.ptr.as_ptr().sub(offset), layout);
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for AliasableThinBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: We own the data, so we can return a reference to it.
+        unsafe { &*self.value_ptr() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for AliasableThinBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: We own the data, so we can return a reference to it.
+        unsafe { &mut *self.value_ptr() }
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for AliasableThinBox<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> AsMut<T> for AliasableThinBox<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut *self
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for AliasableThinBox<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_ref(), f)
+    }
+}
+
+impl<T: ?Sized> fmt::Display for AliasableThinBox<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_ref(), f)
+    }
+}
+
+unsafe impl<T: ?Sized> Send for AliasableThinBox<T> where T: Send {}
+unsafe impl<T: ?Sized> Sync for AliasableThinBox<T> where T: Sync {}
+
+impl<T: Default> Default for AliasableThinBox<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: PartialEq + ?Sized> PartialEq for AliasableThinBox<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq + ?Sized> Eq for AliasableThinBox<T> {}
+
+impl<T: PartialOrd + ?Sized> PartialOrd for AliasableThinBox<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord + ?Sized> Ord for AliasableThinBox<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash + ?Sized> Hash for AliasableThinBox<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+/// Returns the layout of an allocation holding the header followed by a value
+/// of the given layout, and the offset of the value within it. The header ends
+/// right at the value, as the offset is a multiple of the header's size.
+fn layout_with_header(value: Layout) -> (Layout, usize) {
+    let (layout, offset) = Layout::new::<Header>()
+        .extend(value)
+        .expect("capacity overflow");
+    (layout.pad_to_align(), offset)
+}
+
+/// Returns a pointer to the header before the value at `value`.
+///
+/// # Safety
+///
+/// `value` must point to a value in an allocation made with
+/// [`layout_with_header`].
+unsafe fn header(value: *mut u8) -> *mut Header {
+    value.sub(size_of::<Header>()).cast()
+}
+
+/// Allocates memory for the (never zero-sized) `layout`.
+unsafe fn allocate(layout: Layout) -> NonNull<u8> {
+    match NonNull::new(alloc(layout)) {
+        Some(ptr) => ptr,
+        None => handle_alloc_error(layout),
+    }
+}
+
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl<T: ?Sized> crate::StableDeref for AliasableThinBox<T> {}
+
+#[cfg(feature = "aliasable_deref_trait")]
+unsafe impl<T: ?Sized> crate::AliasableDeref for AliasableThinBox<T> {}
+
+#[cfg(feature = "unsize")]
+unsafe impl<T, U: ?Sized> unsize::CoerciblePtr<U> for AliasableThinBox<T> {
+    type Pointee = T;
+    type Output = AliasableThinBox<U>;
+
+    fn as_sized_ptr(&mut self) -> *mut T {
+        self.value_ptr()
+    }
+
+    unsafe fn replace_ptr(self, new: *mut U) -> AliasableThinBox<U> {
+        // Ensure we don't drop `self` as we are transferring the allocation and
+        // we don't want a use after free.
+        core::mem::forget(self);
+        // Write the metadata of the new pointer into the header, as the value
+        // keeps its address and layout.
+        AliasableThinBox::from_parts(new)
+    }
+}
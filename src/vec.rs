@@ -1,17 +1,28 @@
 //! Aliasable `Vec`.
 
-use core::cmp::Ordering;
+use core::alloc::Layout;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::{self, Ordering};
 use core::hash::{Hash, Hasher};
 use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+use core::task::{Context, Poll};
 use core::{fmt, mem, slice};
 
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, realloc};
+
 pub use alloc::vec::Vec as UniqueVec;
 
 /// Basic aliasable (non `core::ptr::Unique`) alternative to
 /// [`alloc::vec::Vec`].
+///
+/// The buffer is allocated exactly as a [`UniqueVec`] would allocate it, so
+/// the two convert into each other without reallocating. Zero-sized types and
+/// empty buffers never allocate: the pointer is dangling, and the capacity of
+/// a vec of zero-sized types is always `usize::MAX`.
 pub struct AliasableVec<T> {
     ptr: NonNull<T>,
     len: usize,
@@ -19,6 +30,32 @@ pub struct AliasableVec<T> {
 }
 
 impl<T> AliasableVec<T> {
+    /// Constructs a new, empty `AliasableVec` without allocating.
+    ///
+    /// Like [`UniqueVec::new`], the buffer pointer is dangling and the
+    /// capacity is zero, or `usize::MAX` for zero-sized types.
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            cap: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+        }
+    }
+
+    /// Constructs a new, empty `AliasableVec` which can hold at least
+    /// `capacity` elements without reallocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity in bytes overflows `isize::MAX`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        if capacity > vec.cap {
+            vec.grow_to(capacity);
+        }
+        vec
+    }
+
     /// Returns the number of elements in the vector, also referred to as its
     /// ‘length’.
     pub fn len(&self) -> usize {
@@ -45,6 +82,74 @@ impl<T> AliasableVec<T> {
         self.len == 0
     }
 
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// This may move the buffer, invalidating pointers to the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity in bytes overflows `isize::MAX`.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        // A vec of zero-sized types always has a capacity of `usize::MAX`, so
+        // it never gets past this check.
+        if required <= self.cap {
+            return;
+        }
+        let new_cap = cmp::max(cmp::max(required, self.cap.saturating_mul(2)), 4);
+        self.grow_to(new_cap);
+    }
+
+    /// Appends an element to the back of the vec.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity in bytes overflows `isize::MAX`.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+        // SAFETY: There is spare capacity, so the slot after the last element
+        // is uninitialized. For zero-sized types the write is a no-op on the
+        // dangling pointer.
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// Removes the last element of the vec and returns it, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The slot was initialized and is no longer tracked as such.
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    /// Shortens the vec, keeping the first `len` elements and dropping the
+    /// rest.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len` is within the initialized elements.
+            unsafe { self.ptr.as_ptr().add(len) },
+            self.len - len,
+        );
+        // Update the length first, so a panicking drop never leaves it covering
+        // a dropped element.
+        self.len = len;
+        // SAFETY: The elements were initialized and are no longer tracked.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Drops all the elements of the vec, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
     /// Construct an `AliasableVec` from a [`UniqueVec`].
     pub fn from_unique(unique: UniqueVec<T>) -> Self {
         // Ensure we don't drop `self` as we are transferring the allocation and
@@ -56,11 +161,17 @@ impl<T> AliasableVec<T> {
         let len = unique.len();
         let cap = unique.capacity();
 
-        // SAFETY: The pointer returned by a vec is never null.
-        let ptr = unsafe { NonNull::new_unchecked(ptr) };
-
         // Return the aliasable vec.
-        Self { ptr, len, cap }
+        if size_of::<T>() == 0 {
+            // Nothing is allocated, so the pointer is only ever dangling.
+            Self { len, ..Self::new() }
+        } else if cap == 0 {
+            Self::new()
+        } else {
+            // SAFETY: The pointer to an allocated buffer is never null.
+            let ptr = unsafe { NonNull::new_unchecked(ptr) };
+            Self { ptr, len, cap }
+        }
     }
 
     /// Consumes the [`AliasableVec`] and converts it back into a
@@ -95,6 +206,65 @@ impl<T> AliasableVec<T> {
         }
     }
 
+    /// Decomposes `self` into its raw pointer, length and capacity without
+    /// dropping it.
+    pub(crate) fn into_raw_parts(self) -> (NonNull<T>, usize, usize) {
+        let this = ManuallyDrop::new(self);
+        (this.ptr, this.len, this.cap)
+    }
+
+    /// Reassembles an `AliasableVec` from the parts returned by
+    /// [`AliasableVec::into_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// The parts must have come from `into_raw_parts` for the same `T`, with
+    /// `len` optionally changed to reflect the number of initialized elements.
+    pub(crate) unsafe fn from_raw_parts(ptr: NonNull<T>, len: usize, cap: usize) -> Self {
+        Self { ptr, len, cap }
+    }
+
+    /// Temporarily converts `self` into a [`UniqueVec`] for the duration of
+    /// `f`, converting it back afterwards even if `f` panics.
+    pub(crate) fn with_unique<R>(&mut self, f: impl FnOnce(&mut UniqueVec<T>) -> R) -> R {
+        struct Guard<'a, T>(&'a mut AliasableVec<T>, UniqueVec<T>);
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                *self.0 = AliasableVec::from_unique(mem::take(&mut self.1));
+            }
+        }
+
+        let taken = Self::into_unique(mem::take(self));
+        let mut guard = Guard(self, taken);
+
+        f(&mut guard.1)
+    }
+
+    /// Reallocates the buffer to hold exactly `new_cap` elements, which must
+    /// be more than the current capacity.
+    fn grow_to(&mut self, new_cap: usize) {
+        debug_assert!(new_cap > self.cap && size_of::<T>() != 0);
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        // SAFETY: `T` is not zero-sized and `new_cap` is non-zero, so the new
+        // layout is not zero-sized. A non-zero capacity means the buffer was
+        // allocated with the layout of that capacity.
+        let new = unsafe {
+            if self.cap == 0 {
+                alloc(new_layout)
+            } else {
+                realloc(
+                    self.ptr.as_ptr().cast(),
+                    Layout::array::<T>(self.cap).unwrap_unchecked(),
+                    new_layout.size(),
+                )
+            }
+        };
+        self.ptr = NonNull::new(new)
+            .unwrap_or_else(|| handle_alloc_error(new_layout))
+            .cast();
+        self.cap = new_cap;
+    }
+
     #[inline]
     unsafe fn reclaim_as_unique_vec(&mut self) -> UniqueVec<T> {
         UniqueVec::from_raw_parts(self.ptr.as_ptr(), self.len, self.cap)
@@ -115,12 +285,61 @@ impl<T> From<AliasableVec<T>> for UniqueVec<T> {
     }
 }
 
+impl<T: Clone> From<&[T]> for AliasableVec<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_unique(UniqueVec::from(slice))
+    }
+}
+
+impl<T: Clone> From<&mut [T]> for AliasableVec<T> {
+    #[inline]
+    fn from(slice: &mut [T]) -> Self {
+        Self::from_unique(UniqueVec::from(slice))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for AliasableVec<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Self::from_unique(UniqueVec::from(array))
+    }
+}
+
+impl From<&str> for AliasableVec<u8> {
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self::from(s.as_bytes())
+    }
+}
+
 impl<T> Drop for AliasableVec<T> {
     fn drop(&mut self) {
+        // Frees the buffer once the elements are dropped, even if dropping one
+        // of them panics.
+        struct Dealloc<T>(NonNull<T>, usize);
+        impl<T> Drop for Dealloc<T> {
+            fn drop(&mut self) {
+                if size_of::<T>() != 0 && self.1 != 0 {
+                    // SAFETY: A non-zero capacity of a non zero-sized type
+                    // means the buffer was allocated with the layout of that
+                    // capacity.
+                    unsafe {
+                        dealloc(
+                            self.0.as_ptr().cast(),
+                            Layout::array::<T>(self.1).unwrap_unchecked(),
+                        );
+                    }
+                }
+            }
+        }
+
+        let _dealloc = Dealloc(self.ptr, self.cap);
         // SAFETY: As `self` is being dropped we can safely assume any aliasing
-        // has ended and convert the aliasable vec back to into an unique vec to
-        // handle the deallocation.
-        let _vec = unsafe { self.reclaim_as_unique_vec() };
+        // has ended, and the first `len` elements are initialized.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+        }
     }
 }
 
@@ -144,7 +363,7 @@ impl<T> DerefMut for AliasableVec<T> {
 
 impl<T> AsRef<[T]> for AliasableVec<T> {
     fn as_ref(&self) -> &[T] {
-        &*self
+        self
     }
 }
 
@@ -154,6 +373,20 @@ impl<T> AsMut<[T]> for AliasableVec<T> {
     }
 }
 
+impl<T> Borrow<[T]> for AliasableVec<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> BorrowMut<[T]> for AliasableVec<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
 impl<T> fmt::Debug for AliasableVec<T>
 where
     T: fmt::Debug,
@@ -169,7 +402,7 @@ unsafe impl<T> Sync for AliasableVec<T> where T: Sync {}
 impl<T> Default for AliasableVec<T> {
     #[inline]
     fn default() -> Self {
-        Self::from_unique(UniqueVec::new())
+        Self::new()
     }
 }
 
@@ -180,22 +413,14 @@ impl<T: Clone> Clone for AliasableVec<T> {
     }
     #[inline]
     fn clone_from(&mut self, source: &Self) {
-        struct Guard<'a, T>(&'a mut AliasableVec<T>, UniqueVec<T>);
-        impl<T> Drop for Guard<'_, T> {
-            fn drop(&mut self) {
-                *self.0 = AliasableVec::from_unique(mem::take(&mut self.1));
-            }
-        }
-
-        let taken = Self::into_unique(mem::take(self));
-        let mut guard = Guard(self, taken);
-
-        guard.1.truncate(source.len);
+        self.with_unique(|vec| {
+            vec.truncate(source.len);
 
-        let (init, tail) = source.split_at(guard.1.len());
+            let (init, tail) = source.split_at(vec.len());
 
-        guard.1.clone_from_slice(init);
-        guard.1.extend_from_slice(tail);
+            vec.clone_from_slice(init);
+            vec.extend_from_slice(tail);
+        });
     }
 }
 
@@ -206,6 +431,13 @@ impl<T: PartialEq<U>, U> PartialEq<AliasableVec<U>> for AliasableVec<T> {
     }
 }
 
+impl_slice_eq!([] AliasableVec<T>, [U]);
+impl_slice_eq!(['a,] AliasableVec<T>, &'a [U]);
+impl_slice_eq!(['a,] AliasableVec<T>, &'a mut [U]);
+impl_slice_eq!([const N: usize,] AliasableVec<T>, [U; N]);
+impl_slice_eq!(['a, const N: usize,] AliasableVec<T>, &'a [U; N]);
+impl_slice_eq!([] AliasableVec<T>, UniqueVec<U>);
+
 impl<T: Eq> Eq for AliasableVec<T> {}
 
 impl<T: PartialOrd> PartialOrd for AliasableVec<T> {
@@ -215,6 +447,11 @@ impl<T: PartialOrd> PartialOrd for AliasableVec<T> {
     }
 }
 
+impl_slice_ord!([] AliasableVec<T>, [T]);
+impl_slice_ord!(['a,] AliasableVec<T>, &'a [T]);
+impl_slice_ord!([const N: usize,] AliasableVec<T>, [T; N]);
+impl_slice_ord!([] AliasableVec<T>, UniqueVec<T>);
+
 impl<T: Ord> Ord for AliasableVec<T> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -234,3 +471,238 @@ unsafe impl<T> crate::StableDeref for AliasableVec<T> {}
 
 #[cfg(feature = "aliasable_deref_trait")]
 unsafe impl<T> crate::AliasableDeref for AliasableVec<T> {}
+
+#[cfg(feature = "std")]
+impl std::io::Write for AliasableVec<u8> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.with_unique(|vec| vec.extend_from_slice(buf));
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.with_unique(|vec| vec.extend_from_slice(buf));
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytes")]
+unsafe impl bytes::BufMut for AliasableVec<u8> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX.unsigned_abs() - self.len
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let remaining = self.cap - self.len;
+        assert!(
+            cnt <= remaining,
+            "cannot advance past `remaining_mut`: {:?} <= {:?}",
+            cnt,
+            remaining
+        );
+        self.len += cnt;
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        if self.cap == self.len {
+            self.with_unique(|vec| vec.reserve(64));
+        }
+        // SAFETY: The spare capacity is within the allocation, and is only
+        // ever written to through the returned `UninitSlice`.
+        unsafe {
+            bytes::buf::UninitSlice::from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len),
+                self.cap - self.len,
+            )
+        }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.with_unique(|vec| vec.extend_from_slice(src));
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<AliasableVec<u8>> for bytes::Bytes {
+    /// Converts the vec into [`bytes::Bytes`] without copying, keeping the
+    /// aliasable vec alive as the owner of the buffer.
+    #[inline]
+    fn from(aliasable: AliasableVec<u8>) -> Self {
+        bytes::Bytes::from_owner(aliasable)
+    }
+}
+
+unsafe impl crate::io_buf::IoBuf for AliasableVec<u8> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.cap
+    }
+}
+
+unsafe impl crate::io_buf::IoBufMut for AliasableVec<u8> {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.cap);
+        self.len = len;
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl futures_io::AsyncWrite for AliasableVec<u8> {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(std::io::Write::write(self.get_mut(), buf))
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl tokio::io::AsyncWrite for AliasableVec<u8> {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(std::io::Write::write(self.get_mut(), buf))
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> zeroize::Zeroize for AliasableVec<T> {
+    /// Zeroizes the elements and then the entire spare capacity of the vec,
+    /// leaving it empty.
+    fn zeroize(&mut self) {
+        self.with_unique(zeroize::Zeroize::zeroize);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for AliasableVec<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueVec::arbitrary(u).map(Self::from_unique)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        UniqueVec::arbitrary_take_rest(u).map(Self::from_unique)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UniqueVec::<T>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary for AliasableVec<T> {
+    type Parameters = <UniqueVec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = proptest::strategy::Map<
+        proptest::arbitrary::StrategyFor<UniqueVec<T>>,
+        fn(UniqueVec<T>) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::arbitrary::any_with::<UniqueVec<T>>(args),
+            Self::from_unique,
+        )
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize> borsh::BorshSerialize for AliasableVec<T> {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        (**self).serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshDeserialize> borsh::BorshDeserialize for AliasableVec<T> {
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        UniqueVec::deserialize_reader(reader).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: bincode::Encode> bincode::Encode for AliasableVec<T> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        (**self).encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context, T: bincode::Decode<Context>> bincode::Decode<Context> for AliasableVec<T> {
+    #[inline]
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueVec::decode(decoder).map(Self::from_unique)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, T> bincode::BorrowDecode<'de, Context> for AliasableVec<T>
+where
+    T: bincode::BorrowDecode<'de, Context>,
+{
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        UniqueVec::borrow_decode(decoder).map(Self::from_unique)
+    }
+}
@@ -0,0 +1,373 @@
+//! Aliasable `VecDeque`.
+
+use core::cmp::{self, Ordering};
+use core::hash::{Hash, Hasher};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::{fmt, ptr, slice};
+
+use crate::vec::{AliasableVec, UniqueVec};
+
+pub use alloc::collections::VecDeque as UniqueVecDeque;
+
+/// Basic aliasable (non `core::ptr::Unique`) alternative to
+/// [`alloc::collections::VecDeque`].
+///
+/// Elements are never moved by pushing or popping unless the buffer has to
+/// grow, so pointers to elements stay valid as long as the deque has spare
+/// capacity. Growing and [`AliasableVecDeque::make_contiguous`] may move
+/// elements.
+pub struct AliasableVecDeque<T> {
+    // The allocation, whose length is always zero as the initialized elements
+    // are tracked by `head` and `len`.
+    buf: AliasableVec<T>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> AliasableVecDeque<T> {
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the deque can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Returns a reference to the element at `index`, where index `0` is the
+    /// front of the deque.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            // SAFETY: The index is within the initialized elements.
+            Some(unsafe { &*self.slot_ptr(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, where index `0`
+    /// is the front of the deque.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            // SAFETY: The index is within the initialized elements.
+            Some(unsafe { &mut *self.slot_ptr(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is
+    /// empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is
+    /// empty.
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Appends an element to the back of the deque.
+    pub fn push_back(&mut self, value: T) {
+        self.grow_if_full();
+        // SAFETY: There is spare capacity, so the slot after the last element
+        // is uninitialized.
+        unsafe { self.slot_ptr(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// Prepends an element to the front of the deque.
+    pub fn push_front(&mut self, value: T) {
+        self.grow_if_full();
+        self.head = self.wrap_sub(self.head, 1);
+        self.len += 1;
+        // SAFETY: There was spare capacity, so the slot before the first
+        // element was uninitialized.
+        unsafe { self.slot_ptr(0).write(value) };
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is
+    /// empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The slot was initialized and is no longer tracked as such.
+        Some(unsafe { self.slot_ptr(self.len).read() })
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is
+    /// empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: The slot was initialized and is no longer tracked as such.
+        let value = unsafe { self.slot_ptr(0).read() };
+        self.head = self.wrap_add(self.head, 1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the
+    /// deque.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_ranges();
+        let ptr = self.buf.as_ptr();
+        // SAFETY: Both ranges are within the initialized elements.
+        unsafe {
+            (
+                slice::from_raw_parts(ptr.add(front.0), front.1),
+                slice::from_raw_parts(ptr, back),
+            )
+        }
+    }
+
+    /// Returns a pair of mutable slices which contain, in order, the contents
+    /// of the deque.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_ranges();
+        let ptr = self.buf.as_mut_ptr();
+        // SAFETY: Both ranges are within the initialized elements and never
+        // overlap.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(front.0), front.1),
+                slice::from_raw_parts_mut(ptr, back),
+            )
+        }
+    }
+
+    /// Rearranges the elements of the deque so they are stored contiguously,
+    /// returning them as a mutable slice.
+    ///
+    /// Elements are only moved if they currently wrap around the end of the
+    /// buffer.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.slice_ranges().1 != 0 {
+            self.rotate_to_start();
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Construct an `AliasableVecDeque` from a [`UniqueVecDeque`].
+    ///
+    /// This never reallocates, but may move the elements so that they are
+    /// stored contiguously from the start of the buffer.
+    pub fn from_unique(unique: UniqueVecDeque<T>) -> Self {
+        let mut vec = UniqueVec::from(unique);
+        let len = vec.len();
+        // SAFETY: The elements are now owned by the aliasable deque, which
+        // tracks them with `head` and `len`.
+        unsafe { vec.set_len(0) };
+        Self {
+            buf: AliasableVec::from_unique(vec),
+            head: 0,
+            len,
+        }
+    }
+
+    /// Consumes the [`AliasableVecDeque`] and converts it back into a
+    /// non-aliasable [`UniqueVecDeque`].
+    ///
+    /// This never reallocates, but may move the elements so that they are
+    /// stored contiguously from the start of the buffer.
+    pub fn into_unique(aliasable: AliasableVecDeque<T>) -> UniqueVecDeque<T> {
+        // Ensure we don't drop `self` as we are transferring the allocation and
+        // we don't want a double free.
+        let mut aliasable = ManuallyDrop::new(aliasable);
+        if aliasable.head != 0 {
+            aliasable.rotate_to_start();
+        }
+        let mut vec = AliasableVec::into_unique(mem::take(&mut aliasable.buf));
+        // SAFETY: The elements are stored contiguously from the start of the
+        // buffer.
+        unsafe { vec.set_len(aliasable.len) };
+        UniqueVecDeque::from(vec)
+    }
+
+    /// Returns the physical index of the slot `index` elements after the
+    /// front, without overflowing for zero-sized types.
+    #[inline]
+    fn wrap_add(&self, physical: usize, index: usize) -> usize {
+        let cap = self.capacity();
+        if index >= cap - physical {
+            index - (cap - physical)
+        } else {
+            physical + index
+        }
+    }
+
+    #[inline]
+    fn wrap_sub(&self, physical: usize, index: usize) -> usize {
+        if index > physical {
+            self.capacity() - (index - physical)
+        } else {
+            physical - index
+        }
+    }
+
+    /// Returns a pointer to the slot `index` elements after the front.
+    #[inline]
+    fn slot_ptr(&self, index: usize) -> *mut T {
+        // SAFETY: The wrapped index is always within the allocation.
+        unsafe {
+            self.buf
+                .as_ptr()
+                .cast_mut()
+                .add(self.wrap_add(self.head, index))
+        }
+    }
+
+    /// Returns the `(start, len)` of the front slice and the length of the
+    /// back slice, which always starts at the beginning of the buffer.
+    #[inline]
+    fn slice_ranges(&self) -> ((usize, usize), usize) {
+        let to_end = self.capacity() - self.head;
+        if self.len <= to_end {
+            ((self.head, self.len), 0)
+        } else {
+            ((self.head, to_end), self.len - to_end)
+        }
+    }
+
+    /// Moves the elements so that the front is stored at the start of the
+    /// buffer.
+    fn rotate_to_start(&mut self) {
+        let cap = self.capacity();
+        let ptr = self.buf.as_mut_ptr().cast::<MaybeUninit<T>>();
+        // SAFETY: `MaybeUninit` slots may be moved regardless of whether they
+        // are initialized.
+        unsafe { slice::from_raw_parts_mut(ptr, cap) }.rotate_left(self.head);
+        self.head = 0;
+    }
+
+    fn grow_if_full(&mut self) {
+        if self.len < self.capacity() {
+            return;
+        }
+        let new_cap = cmp::max(
+            self.capacity().checked_mul(2).expect("capacity overflow"),
+            4,
+        );
+        let mut vec = UniqueVec::with_capacity(new_cap);
+        let (front, back) = self.as_slices();
+        // SAFETY: The new buffer has room for all the elements, which are moved
+        // out of the old buffer and will never be dropped from it as it has a
+        // length of zero.
+        unsafe {
+            let dst = vec.as_mut_ptr();
+            ptr::copy_nonoverlapping(front.as_ptr(), dst, front.len());
+            ptr::copy_nonoverlapping(back.as_ptr(), dst.add(front.len()), back.len());
+        }
+        self.buf = AliasableVec::from_unique(vec);
+        self.head = 0;
+    }
+}
+
+impl<T> From<UniqueVecDeque<T>> for AliasableVecDeque<T> {
+    #[inline]
+    fn from(unique: UniqueVecDeque<T>) -> Self {
+        Self::from_unique(unique)
+    }
+}
+
+impl<T> From<AliasableVecDeque<T>> for UniqueVecDeque<T> {
+    #[inline]
+    fn from(aliasable: AliasableVecDeque<T>) -> Self {
+        AliasableVecDeque::into_unique(aliasable)
+    }
+}
+
+impl<T> Drop for AliasableVecDeque<T> {
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        // SAFETY: The elements are initialized and are never used again. The
+        // allocation itself is freed when `buf` is dropped.
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T> fmt::Debug for AliasableVecDeque<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (front, back) = self.as_slices();
+        f.debug_list().entries(front).entries(back).finish()
+    }
+}
+
+impl<T> Default for AliasableVecDeque<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_unique(UniqueVecDeque::new())
+    }
+}
+
+impl<T: Clone> Clone for AliasableVecDeque<T> {
+    fn clone(&self) -> Self {
+        let (front, back) = self.as_slices();
+        let mut vec = UniqueVec::with_capacity(self.len);
+        vec.extend_from_slice(front);
+        vec.extend_from_slice(back);
+        Self::from_unique(vec.into())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<AliasableVecDeque<U>> for AliasableVecDeque<T> {
+    fn eq(&self, other: &AliasableVecDeque<U>) -> bool {
+        let (a_front, a_back) = self.as_slices();
+        let (b_front, b_back) = other.as_slices();
+        self.len == other.len
+            && a_front
+                .iter()
+                .chain(a_back)
+                .eq(b_front.iter().chain(b_back))
+    }
+}
+
+impl<T: Eq> Eq for AliasableVecDeque<T> {}
+
+impl<T: PartialOrd> PartialOrd for AliasableVecDeque<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a_front, a_back) = self.as_slices();
+        let (b_front, b_back) = other.as_slices();
+        a_front
+            .iter()
+            .chain(a_back)
+            .partial_cmp(b_front.iter().chain(b_back))
+    }
+}
+
+impl<T: Ord> Ord for AliasableVecDeque<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a_front, a_back) = self.as_slices();
+        let (b_front, b_back) = other.as_slices();
+        a_front
+            .iter()
+            .chain(a_back)
+            .cmp(b_front.iter().chain(b_back))
+    }
+}
+
+impl<T: Hash> Hash for AliasableVecDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashed the same way as `VecDeque`, so that both hash equally.
+        state.write_usize(self.len);
+        let (front, back) = self.as_slices();
+        front.iter().chain(back).for_each(|elem| elem.hash(state));
+    }
+}
@@ -0,0 +1,101 @@
+//! Wrapper zeroizing aliasable containers on drop.
+
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use zeroize::Zeroize;
+
+/// Wrapper which zeroizes the wrapped value when it is dropped, before its
+/// memory is freed.
+///
+/// Wrapping an aliasable container such as `AliasableVec<u8>` keeps raw
+/// pointers into its buffer valid when the wrapper is moved, while still
+/// guaranteeing the buffer is wiped.
+pub struct ZeroizeOnDrop<T: Zeroize>(T);
+
+impl<T: Zeroize> ZeroizeOnDrop<T> {
+    /// Wraps `value`, zeroizing it on drop.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> From<T> for ZeroizeOnDrop<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize> Drop for ZeroizeOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> zeroize::ZeroizeOnDrop for ZeroizeOnDrop<T> {}
+
+impl<T: Zeroize> Zeroize for ZeroizeOnDrop<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Deref for ZeroizeOnDrop<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> DerefMut for ZeroizeOnDrop<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> AsRef<T> for ZeroizeOnDrop<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> AsMut<T> for ZeroizeOnDrop<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize + fmt::Debug> fmt::Debug for ZeroizeOnDrop<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ZeroizeOnDrop").field(&self.0).finish()
+    }
+}
+
+impl<T: Zeroize + Default> Default for ZeroizeOnDrop<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for ZeroizeOnDrop<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T: Zeroize + PartialEq> PartialEq for ZeroizeOnDrop<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Zeroize + Eq> Eq for ZeroizeOnDrop<T> {}
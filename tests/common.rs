@@ -5,7 +5,7 @@ use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
 
-#[allow(clippy::eq_op)]
+#[allow(clippy::eq_op, clippy::nonminimal_bool)]
 pub fn check_ordering<T: PartialEq + Eq + PartialOrd + Ord + Debug>(a: T, b: T) {
     assert_eq!(a, a);
     assert_eq!(b, b);
@@ -0,0 +1,44 @@
+#![cfg(all(feature = "alloc", feature = "arbitrary"))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec};
+use arbitrary::{Arbitrary, Unstructured};
+
+const DATA: &[u8] = b"\x05\x01\x02\x03\x04\x05\x06\x07hello world";
+
+fn generate<'a, T: Arbitrary<'a>>() -> T {
+    T::arbitrary(&mut Unstructured::new(DATA)).unwrap()
+}
+
+#[test]
+fn test_vec() {
+    let aliasable: AliasableVec<u16> = generate();
+    let unique: Vec<u16> = generate();
+    assert_eq!(&*aliasable, &*unique);
+    let aliasable = AliasableVec::<u8>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+    let unique = Vec::<u8>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+    assert_eq!(&*aliasable, &*unique);
+    assert_eq!(AliasableVec::<u8>::size_hint(0), Vec::<u8>::size_hint(0));
+}
+
+#[test]
+fn test_box() {
+    let aliasable: AliasableBox<u32> = generate();
+    let unique: Box<u32> = generate();
+    assert_eq!(*aliasable, *unique);
+    let aliasable = AliasableBox::<Vec<u8>>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+    let unique = Box::<Vec<u8>>::arbitrary_take_rest(Unstructured::new(DATA)).unwrap();
+    assert_eq!(*aliasable, *unique);
+    let aliasable: AliasableBox<[u8]> = generate();
+    let unique: Box<[u8]> = generate();
+    assert_eq!(*aliasable, *unique);
+    let aliasable: AliasableBox<str> = generate();
+    let unique: Box<str> = generate();
+    assert_eq!(*aliasable, *unique);
+}
+
+#[test]
+fn test_string() {
+    let aliasable: AliasableString = generate();
+    let unique: String = generate();
+    assert_eq!(&*aliasable, unique);
+}
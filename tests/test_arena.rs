@@ -0,0 +1,111 @@
+#![cfg(feature = "alloc")]
+
+use aliasable::prelude::{AliasableMut, Arena};
+use core::cell::Cell;
+
+#[test]
+fn test_alloc() {
+    let arena = Arena::new();
+    assert!(arena.is_empty());
+    let mut a = arena.alloc(1);
+    let b = arena.alloc(2);
+    *a += 10;
+    assert_eq!(*a, 11);
+    assert_eq!(*b, 2);
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn test_stable_addresses() {
+    let arena = Arena::with_capacity(1);
+    let first = arena.alloc(0);
+    let ptr: *const i32 = &*first;
+    // Force the arena to allocate several new chunks.
+    let rest: Vec<_> = (1..100).map(|i| arena.alloc(i)).collect();
+    assert_eq!(&*first as *const i32, ptr);
+    assert_eq!(*first, 0);
+    assert!(rest.iter().enumerate().all(|(i, v)| **v == i as i32 + 1));
+}
+
+#[test]
+fn test_cycle() {
+    struct Node<'a> {
+        value: i32,
+        next: Option<AliasableMut<'a, Node<'a>>>,
+        prev: Cell<Option<&'a Node<'a>>>,
+    }
+
+    let arena = Arena::new();
+    let b = arena.alloc(Node {
+        value: 2,
+        next: None,
+        prev: Cell::new(None),
+    });
+    let a = arena.alloc(Node {
+        value: 1,
+        next: Some(b),
+        prev: Cell::new(None),
+    });
+    let a: &Node<'_> = AliasableMut::into_unique(a);
+    let b = a.next.as_deref().unwrap();
+    b.prev.set(Some(a));
+    a.prev.set(Some(b));
+    assert_eq!(b.prev.get().unwrap().value, 1);
+    assert_eq!(a.prev.get().unwrap().value, 2);
+}
+
+#[test]
+fn test_alloc_slice() {
+    let arena = Arena::with_capacity(2);
+    let _ = arena.alloc(String::from("a"));
+    let mut slice = arena.alloc_slice(&[String::from("b"), String::from("c")]);
+    slice[0].push('!');
+    assert_eq!(&*slice, ["b!", "c"]);
+    assert_eq!(arena.len(), 3);
+    assert_eq!(arena.alloc_slice(&[]).len(), 0);
+}
+
+#[test]
+fn test_alloc_str() {
+    let arena = Arena::new();
+    let mut s = arena.alloc_str("hello");
+    s.make_ascii_uppercase();
+    assert_eq!(&*s, "HELLO");
+    assert_eq!(&*arena.alloc_str("world"), "world");
+}
+
+#[test]
+fn test_drop() {
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let arena = Arena::with_capacity(3);
+    for _ in 0..10 {
+        let _ = arena.alloc(Counted(&drops));
+    }
+    assert_eq!(drops.get(), 0);
+    drop(arena);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn test_zst() {
+    let arena = Arena::new();
+    for _ in 0..100 {
+        let _ = arena.alloc(());
+    }
+    assert_eq!(arena.alloc_slice(&[(); 3]).len(), 3);
+    assert_eq!(arena.len(), 103);
+}
+
+#[test]
+fn test_debug() {
+    let arena = Arena::new();
+    let _ = arena.alloc(1);
+    assert_eq!(format!("{:?}", arena), "Arena { len: 1 }");
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use aliasable::prelude::{AliasableArrayString, CapacityError};
+use core::fmt::Write;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_push() {
+    let mut s = AliasableArrayString::<7>::new();
+    s.push_str("ab");
+    s.push('é');
+    assert_eq!(s, "abé");
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.try_push_str("hello"), Err(CapacityError));
+    assert_eq!(s.try_push('€'), Ok(()));
+    assert_eq!(s.try_push('x'), Err(CapacityError));
+    assert_eq!(s.pop(), Some('€'));
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s.as_str(), "ab");
+    s.clear();
+    assert!(s.is_empty());
+    assert_eq!(s.pop(), None);
+}
+
+#[test]
+#[should_panic(expected = "not a char boundary")]
+fn test_truncate_boundary() {
+    let mut s = AliasableArrayString::<4>::new();
+    s.push('é');
+    s.truncate(1);
+}
+
+#[test]
+fn test_write() {
+    let mut s = AliasableArrayString::<4>::new();
+    write!(s, "{}", 12).unwrap();
+    assert_eq!(s, "12");
+    assert!(write!(s, "{}", 345).is_err());
+}
+
+#[test]
+fn test_fmt() {
+    let mut s = AliasableArrayString::<4>::new();
+    s.push_str("hi");
+    assert_eq!(format!("{:?}", s), "\"hi\"");
+    assert_eq!(format!("{:>3}", s), " hi");
+}
+
+#[test]
+fn test_cmp() {
+    let mut l = AliasableArrayString::<4>::new();
+    l.push_str("ab");
+    let mut r = AliasableArrayString::<4>::new();
+    r.push_str("b");
+    check_ordering(l, r);
+}
+
+#[test]
+fn test_hash() {
+    let mut s = AliasableArrayString::<4>::new();
+    s.push_str("ab");
+    assert_eq!(hash_of(s), hash_of("ab"));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless() {
+    use core::convert::TryFrom;
+
+    let s = heapless::String::<4>::try_from("abc").unwrap();
+    let aliasable = AliasableArrayString::from(s);
+    assert_eq!(aliasable, "abc");
+    assert_eq!(heapless::String::from(aliasable), "abc");
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec() {
+    let s = arrayvec::ArrayString::<4>::from("abc").unwrap();
+    let aliasable = AliasableArrayString::from(s);
+    assert_eq!(aliasable, "abc");
+    let s: arrayvec::ArrayString<4> = aliasable.into();
+    assert_eq!(s.as_str(), "abc");
+}
+
+#[test]
+fn test_pointers() {
+    let mut s = AliasableArrayString::<8>::new();
+    s.push_str("ab");
+    let first = s.as_ptr();
+    s.push_str("cd");
+    // SAFETY: The string hasn't moved, and pushing doesn't invalidate
+    // pointers to its bytes.
+    assert_eq!(unsafe { *first }, b'a');
+    assert_eq!(s.pop(), Some('d'));
+    // SAFETY: As above.
+    assert_eq!(unsafe { *first.add(1) }, b'b');
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use aliasable::prelude::{AliasableArrayVec, CapacityError};
+use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_push_pop() {
+    let mut v = AliasableArrayVec::<u32, 3>::new();
+    assert_eq!(v.capacity(), 3);
+    v.push(1);
+    v.push(2);
+    assert_eq!(v.try_push(3), Ok(()));
+    assert!(v.is_full());
+    assert_eq!(v.try_push(4), Err(4));
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.pop(), Some(3));
+    v.truncate(1);
+    assert_eq!(v, [1]);
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(v.pop(), None);
+}
+
+#[test]
+#[should_panic(expected = "AliasableArrayVec is full")]
+fn test_push_full() {
+    let mut v = AliasableArrayVec::<u32, 1>::new();
+    v.push(1);
+    v.push(2);
+}
+
+#[test]
+fn test_extend_from_slice() {
+    let mut v = AliasableArrayVec::<u32, 3>::new();
+    assert_eq!(v.try_extend_from_slice(&[1, 2]), Ok(()));
+    assert_eq!(v.try_extend_from_slice(&[3, 4]), Err(CapacityError));
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn test_pointers() {
+    let mut v = AliasableArrayVec::<u32, 4>::new();
+    v.push(1);
+    let first = v.as_mut_ptr();
+    v.push(2);
+    assert_eq!(v.as_ptr(), first);
+    // SAFETY: The vec hasn't moved, and pushing doesn't invalidate pointers
+    // to its elements.
+    assert_eq!(unsafe { *first }, 1);
+    assert_eq!(v.pop(), Some(2));
+    // SAFETY: As above, and the first element is still initialized.
+    unsafe { *first = 10 };
+    v.push(3);
+    // SAFETY: As above.
+    assert_eq!(unsafe { *first }, 10);
+    assert_eq!(v, [10, 3]);
+}
+
+#[test]
+fn test_static() {
+    static EMPTY: AliasableArrayVec<u8, 4> = AliasableArrayVec::new();
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn test_drop() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut v = AliasableArrayVec::<_, 4>::new();
+    v.extend((0..3).map(|_| Counted));
+    drop(v.pop());
+    assert_eq!(DROPS.load(SeqCst), 1);
+    drop(v);
+    assert_eq!(DROPS.load(SeqCst), 3);
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let mut v = AliasableArrayVec::<String, 2>::new();
+    v.push("a".into());
+    assert_eq!(v.clone(), v);
+}
+
+#[test]
+fn test_debug() {
+    let mut v = AliasableArrayVec::<u8, 2>::new();
+    v.push(1);
+    assert_eq!(format!("{:?}", v), "[1]");
+}
+
+#[test]
+fn test_cmp() {
+    let mut l = AliasableArrayVec::<u8, 2>::new();
+    l.push(1);
+    let mut r = l.clone();
+    r.push(1);
+    check_ordering(l, r);
+}
+
+#[test]
+fn test_hash() {
+    let mut v = AliasableArrayVec::<u8, 2>::new();
+    v.push(1);
+    assert_eq!(hash_of(v), hash_of([1u8]));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless() {
+    let mut vec = heapless::Vec::<u8, 4>::new();
+    vec.extend_from_slice(&[1, 2]).unwrap();
+    let aliasable = AliasableArrayVec::from(vec);
+    assert_eq!(aliasable, [1, 2]);
+    let vec = heapless::Vec::from(aliasable);
+    assert_eq!(vec, [1, 2]);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec() {
+    let vec: arrayvec::ArrayVec<String, 4> = vec!["a".into()].into_iter().collect();
+    let aliasable = AliasableArrayVec::from(vec);
+    assert_eq!(aliasable, ["a"]);
+    let vec = arrayvec::ArrayVec::from(aliasable);
+    assert_eq!(&vec[..], ["a"]);
+}
@@ -0,0 +1,52 @@
+#![cfg(all(feature = "alloc", feature = "bincode"))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec};
+use bincode::config::standard;
+
+fn encode<T: bincode::Encode>(value: &T) -> Vec<u8> {
+    bincode::encode_to_vec(value, standard()).unwrap()
+}
+
+fn decode<T: bincode::Decode<()>>(bytes: &[u8]) -> T {
+    let (value, read) = bincode::decode_from_slice(bytes, standard()).unwrap();
+    assert_eq!(read, bytes.len());
+    value
+}
+
+fn borrow_decode<'de, T: bincode::BorrowDecode<'de, ()>>(bytes: &'de [u8]) -> T {
+    bincode::borrow_decode_from_slice(bytes, standard())
+        .unwrap()
+        .0
+}
+
+#[test]
+fn test_vec() {
+    let aliasable = AliasableVec::from_unique(vec![1u32, 300, 70000]);
+    let bytes = encode(&aliasable);
+    assert_eq!(bytes, encode(&vec![1u32, 300, 70000]));
+    assert_eq!(decode::<AliasableVec<u32>>(&bytes), aliasable);
+    assert_eq!(borrow_decode::<AliasableVec<u32>>(&bytes), aliasable);
+}
+
+#[test]
+fn test_string() {
+    let aliasable = AliasableString::from_unique(String::from("hello"));
+    let bytes = encode(&aliasable);
+    assert_eq!(bytes, encode(&String::from("hello")));
+    assert_eq!(decode::<AliasableString>(&bytes), aliasable);
+    assert_eq!(borrow_decode::<AliasableString>(&bytes), aliasable);
+}
+
+#[test]
+fn test_box() {
+    let aliasable = AliasableBox::from_unique(Box::new(7u64));
+    let bytes = encode(&aliasable);
+    assert_eq!(bytes, encode(&Box::new(7u64)));
+    assert_eq!(*decode::<AliasableBox<u64>>(&bytes), 7);
+    assert_eq!(*borrow_decode::<AliasableBox<u64>>(&bytes), 7);
+
+    let bytes = encode(&AliasableBox::from_unique(Box::<str>::from("boxed")));
+    assert_eq!(bytes, encode(&"boxed"));
+    assert_eq!(&*decode::<AliasableBox<str>>(&bytes), "boxed");
+    assert_eq!(&*decode::<AliasableBox<[u8]>>(&bytes), b"boxed");
+}
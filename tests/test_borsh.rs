@@ -0,0 +1,39 @@
+#![cfg(all(feature = "alloc", feature = "borsh"))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec};
+
+#[test]
+fn test_vec() {
+    let aliasable = AliasableVec::from_unique(vec![1u32, 2, 3]);
+    let bytes = borsh::to_vec(&aliasable).unwrap();
+    assert_eq!(bytes, borsh::to_vec(&vec![1u32, 2, 3]).unwrap());
+    let decoded: AliasableVec<u32> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, aliasable);
+}
+
+#[test]
+fn test_string() {
+    let aliasable = AliasableString::from_unique(String::from("hello"));
+    let bytes = borsh::to_vec(&aliasable).unwrap();
+    assert_eq!(bytes, borsh::to_vec("hello").unwrap());
+    let decoded: AliasableString = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, aliasable);
+    assert!(borsh::from_slice::<AliasableString>(b"\x01\x00\x00\x00\xff").is_err());
+}
+
+#[test]
+fn test_box() {
+    let aliasable = AliasableBox::from_unique(Box::new(7u64));
+    let bytes = borsh::to_vec(&aliasable).unwrap();
+    assert_eq!(bytes, borsh::to_vec(&Box::new(7u64)).unwrap());
+    let decoded: AliasableBox<u64> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(*decoded, 7);
+
+    let aliasable = AliasableBox::from_unique(Box::<str>::from("boxed"));
+    let bytes = borsh::to_vec(&aliasable).unwrap();
+    let decoded: AliasableBox<str> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(&*decoded, "boxed");
+    let decoded: AliasableBox<[u8]> =
+        borsh::from_slice(&borsh::to_vec(&[1u8, 2][..]).unwrap()).unwrap();
+    assert_eq!(&*decoded, [1, 2]);
+}
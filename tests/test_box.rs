@@ -1,6 +1,8 @@
+#![cfg(feature = "alloc")]
+
 mod common;
 
-use aliasable::prelude::{AliasableBox, UniqueBox};
+use aliasable::prelude::{AliasableBox, AliasableVec, UniqueBox};
 
 use self::common::{check_ordering, hash_of};
 
@@ -64,6 +66,36 @@ fn test_hash() {
     assert_eq!(hash_of(AliasableBox::from_unique(b.clone())), hash_of(b));
 }
 
+#[test]
+fn test_cmp_foreign() {
+    let aliasable = AliasableBox::from_unique(UniqueBox::new(5));
+    assert_eq!(aliasable, UniqueBox::new(5));
+    assert!(aliasable < UniqueBox::new(6));
+
+    let slice = AliasableBox::from_unique(UniqueBox::<[i32]>::from(&[1, 2][..]));
+    assert_eq!(slice, [1, 2]);
+    assert_eq!(slice, &[1, 2][..]);
+    assert_eq!(slice, vec![1, 2]);
+    assert_eq!(vec![1, 2], slice);
+    assert!(slice < [1, 3]);
+
+    let string = AliasableBox::from_unique(UniqueBox::<str>::from("foo"));
+    assert_eq!(string, "foo");
+    assert_eq!(string, String::from("foo"));
+    assert_eq!("foo", string);
+    assert!(string > "bar");
+}
+
+#[test]
+fn test_borrow() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    let _ = set.insert(AliasableBox::from_unique(UniqueBox::<str>::from("key")));
+    assert!(set.contains("key"));
+    assert!(!set.contains("other"));
+}
+
 #[cfg(feature = "unsize")]
 #[test]
 fn test_unsize() {
@@ -72,3 +104,102 @@ fn test_unsize() {
     let unsized_box: AliasableBox<[u8]> = aliasable.unsize(Coercion::to_slice());
     assert_eq!(*unsized_box, [0, 0]);
 }
+
+#[cfg(feature = "unsize")]
+#[test]
+fn test_unsize_pin() {
+    use core::fmt::Debug;
+    use core::pin::Pin;
+    use unsize::{CoerceUnsize, Coercion};
+    let aliasable = AliasableBox::from_unique_pin(UniqueBox::pin(10));
+    let unsized_pin: Pin<AliasableBox<dyn Debug>> = aliasable.unsize(Coercion::to_debug());
+    assert_eq!(format!("{:?}", unsized_pin), "10");
+}
+
+#[test]
+fn test_from_array() {
+    let aliasable = AliasableBox::from_unique(UniqueBox::new([1u8, 2]));
+    let ptr: *const u8 = aliasable.as_ptr();
+    let slice = <AliasableBox<[u8]>>::from(aliasable);
+    assert_eq!(*slice, [1, 2]);
+    assert_eq!(slice.as_ptr(), ptr);
+}
+
+#[test]
+fn test_display() {
+    let aliasable = AliasableBox::from_unique(UniqueBox::new(10));
+    assert_eq!(format!("{}", aliasable), "10");
+}
+
+#[test]
+fn test_pointer() {
+    let aliasable = AliasableBox::from_unique(UniqueBox::new(10));
+    let ptr: *const i32 = &*aliasable;
+    assert_eq!(format!("{:p}", aliasable), format!("{:p}", ptr));
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(*AliasableBox::from(5), 5);
+    assert_eq!(*AliasableBox::<[i32]>::from(&[1, 2][..]), [1, 2]);
+    assert_eq!(*AliasableBox::<[i32]>::from([1, 2]), [1, 2]);
+    assert_eq!(&*AliasableBox::<str>::from("abc"), "abc");
+
+    let slice = AliasableBox::<[i32]>::from(AliasableVec::from_unique(vec![1, 2]));
+    assert_eq!(*slice, [1, 2]);
+    assert_eq!(AliasableVec::from(slice), [1, 2]);
+}
+
+#[test]
+fn test_try_from() {
+    use core::convert::TryFrom;
+
+    let array = AliasableBox::<[i32; 2]>::try_from(AliasableVec::from_unique(vec![1, 2]));
+    assert_eq!(*array.unwrap(), [1, 2]);
+    let err = AliasableBox::<[i32; 3]>::try_from(AliasableVec::from_unique(vec![1, 2]));
+    assert_eq!(err.unwrap_err(), [1, 2]);
+
+    let slice = AliasableBox::<[i32]>::from([1, 2]);
+    let ptr = slice.as_ptr();
+    let array = AliasableBox::<[i32; 2]>::try_from(slice).unwrap();
+    assert_eq!(array.as_ptr(), ptr);
+    let err = AliasableBox::<[i32; 1]>::try_from(AliasableBox::<[i32]>::from([1, 2]));
+    assert_eq!(err.unwrap_err(), [1, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_read() {
+    use std::io::{BufRead, Cursor, Read};
+    let aliasable = AliasableBox::from_unique(UniqueBox::<[u8]>::from(&b"hello"[..]));
+    let mut cursor = Cursor::new(aliasable);
+    let mut buf = [0; 3];
+    cursor.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hel");
+    assert_eq!(cursor.fill_buf().unwrap(), b"lo");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_error() {
+    use std::error::Error;
+    use std::{fmt, io};
+
+    #[derive(Debug)]
+    struct Outer(io::Error);
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("outer")
+        }
+    }
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let inner = io::Error::other("inner");
+    let aliasable = AliasableBox::from_unique(UniqueBox::new(Outer(inner)));
+    assert_eq!(aliasable.to_string(), "outer");
+    assert_eq!(aliasable.source().unwrap().to_string(), "inner");
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "alloc")]
+
+use aliasable::btree_map::Entry;
+use aliasable::prelude::{AliasableBTreeMap, UniqueBTreeMap};
+
+#[test]
+fn test_new() {
+    let unique: UniqueBTreeMap<_, _> = vec![(1, "a"), (2, "b")].into_iter().collect();
+    let aliasable = AliasableBTreeMap::from_unique(unique.clone());
+    assert_eq!(aliasable.len(), 2);
+    assert_eq!(aliasable[&1], "a");
+    assert_eq!(AliasableBTreeMap::into_unique(aliasable), unique);
+}
+
+#[test]
+fn test_insert_get_remove() {
+    let mut map = AliasableBTreeMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert("a", 1), None);
+    assert_eq!(map.insert("b", 2), None);
+    assert_eq!(map.insert("a", 3), Some(1));
+    assert_eq!(map.get("a"), Some(&3));
+    assert!(map.contains_key("b"));
+    *map.get_mut("b").unwrap() += 10;
+    assert_eq!(map.remove("b"), Some(12));
+    assert_eq!(map.remove("b"), None);
+    assert_eq!(map.len(), 1);
+    map.clear();
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_stable_addresses() {
+    let mut map = AliasableBTreeMap::new();
+    let _ = map.insert(0, 0);
+    let ptr = map.get_ptr(&0).unwrap();
+    // Force the underlying tree to split and move its entries around.
+    for i in 1..1000 {
+        let _ = map.insert(i, i);
+    }
+    let _ = map.insert(0, 42);
+    assert_eq!(map.get(&0).unwrap() as *const i32, ptr);
+    // SAFETY: The value has not been moved or dropped.
+    assert_eq!(unsafe { *ptr }, 42);
+}
+
+#[test]
+fn test_entry() {
+    let mut map = AliasableBTreeMap::new();
+    *map.entry("a").or_insert(1) += 1;
+    *map.entry("a").or_default() += 1;
+    map.entry("b").and_modify(|v| *v = 100).or_insert_with(|| 5);
+    let _ = map.entry("b").and_modify(|v| *v += 1);
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 6);
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"a");
+            assert_eq!(entry.insert(7), 3);
+            assert_eq!(entry.remove(), 7);
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry("c") {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), "c"),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_iter() {
+    let mut map: AliasableBTreeMap<_, _> = vec![(2, 20), (1, 10)].into_iter().collect();
+    for (_, v) in &mut map {
+        *v += 1;
+    }
+    let entries: Vec<_> = map.iter().collect();
+    assert_eq!(entries, [(&1, &11), (&2, &21)]);
+}
+
+#[test]
+fn test_debug() {
+    let map: AliasableBTreeMap<_, _> = vec![(1, "a")].into_iter().collect();
+    assert_eq!(format!("{:?}", map), "{1: \"a\"}");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let map: AliasableBTreeMap<_, _> = vec![(1, "a")].into_iter().collect();
+    assert_eq!(map.clone(), map);
+    assert_eq!(
+        <AliasableBTreeMap<i32, i32>>::default(),
+        AliasableBTreeMap::new()
+    );
+}
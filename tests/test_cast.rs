@@ -0,0 +1,69 @@
+#![cfg(all(feature = "alloc", feature = "bytemuck"))]
+
+use aliasable::cast::{cast_mut, try_cast_box, try_cast_mut, try_cast_slice_box, try_cast_vec};
+use aliasable::prelude::{AliasableBox, AliasableMut, AliasableVec};
+use bytemuck::PodCastError;
+
+#[test]
+fn test_vec() {
+    let aliasable = AliasableVec::from_unique(vec![[1u16, 2], [3, 4]]);
+    let ptr = aliasable.as_ptr();
+    let cast: AliasableVec<u16> = try_cast_vec(aliasable).unwrap();
+    assert_eq!(cast.as_ptr(), ptr.cast());
+    assert_eq!(&*cast, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_vec_mismatch() {
+    let aliasable = AliasableVec::from_unique(vec![1u8, 2, 3, 4]);
+    let ptr = aliasable.as_ptr();
+    let (err, original) = try_cast_vec::<u8, u32>(aliasable).unwrap_err();
+    assert_eq!(err, PodCastError::AlignmentMismatch);
+    assert_eq!(original.as_ptr(), ptr);
+    assert_eq!(&*original, [1, 2, 3, 4]);
+
+    let aliasable = AliasableVec::from_unique(vec![1u16, 2, 3]);
+    let (err, _) = try_cast_vec::<u16, [u16; 2]>(aliasable).unwrap_err();
+    assert_eq!(err, PodCastError::OutputSliceWouldHaveSlop);
+}
+
+#[test]
+fn test_box() {
+    let aliasable = AliasableBox::from_unique(Box::new(1.0f32));
+    let cast: AliasableBox<u32> = try_cast_box(aliasable).unwrap();
+    assert_eq!(*cast, 1.0f32.to_bits());
+    let (err, original) = try_cast_box::<u32, [u32; 2]>(cast).unwrap_err();
+    assert_eq!(err, PodCastError::SizeMismatch);
+    assert_eq!(*original, 1.0f32.to_bits());
+}
+
+#[test]
+fn test_slice_box() {
+    let aliasable = AliasableBox::from_unique(vec![[1u16, 2], [3, 4]].into_boxed_slice());
+    let cast: AliasableBox<[u16]> = try_cast_slice_box(aliasable).unwrap();
+    assert_eq!(&*cast, [1, 2, 3, 4]);
+    let (err, original) = try_cast_slice_box::<u16, u32>(cast).unwrap_err();
+    assert_eq!(err, PodCastError::AlignmentMismatch);
+    assert_eq!(original.len(), 4);
+}
+
+#[test]
+fn test_mut() {
+    let mut value = 0u32;
+    let cast: AliasableMut<'_, [u16; 2]> = cast_mut(AliasableMut::from_unique(&mut value));
+    AliasableMut::into_unique(cast)[0] = 1;
+    assert_eq!(value.to_ne_bytes()[..2], 1u16.to_ne_bytes());
+
+    let (err, original) =
+        try_cast_mut::<u32, [u32; 2]>(AliasableMut::from(&mut value)).unwrap_err();
+    assert_eq!(err, PodCastError::SizeMismatch);
+    *AliasableMut::into_unique(original) = 5;
+    assert_eq!(value, 5);
+}
+
+#[test]
+#[should_panic(expected = "cast_mut: SizeMismatch")]
+fn test_cast_mut_panics() {
+    let mut value = 0u32;
+    let _: AliasableMut<'_, u8> = cast_mut(AliasableMut::from(&mut value));
+}
@@ -0,0 +1,88 @@
+use aliasable::prelude::{AliasableCell, AliasableMut};
+
+#[test]
+fn test_copies() {
+    let mut value = 1;
+    let a = AliasableCell::from_unique(&mut value);
+    let b = a;
+    assert_eq!(a, b);
+    a.set(2);
+    assert_eq!(b.get(), 2);
+    assert_eq!(b.replace(3), 2);
+    assert_eq!(a.update(|x| x * 10), 30);
+    assert_eq!(b.get(), 30);
+    assert_eq!(format!("{:?}", a), "AliasableCell { value: 30 }");
+    assert_eq!(value, 30);
+}
+
+#[test]
+fn test_shared_between_nodes() {
+    // Several structures each holding a copy of the same counter.
+    struct Counter<'a> {
+        total: AliasableCell<'a, u32>,
+    }
+
+    impl Counter<'_> {
+        fn add(&self, n: u32) {
+            let _ = self.total.update(|total| total + n);
+        }
+    }
+
+    let mut total = 0;
+    let cell = AliasableCell::from(&mut total);
+    let counters: Vec<_> = (0..3).map(|_| Counter { total: cell }).collect();
+    for (i, counter) in counters.iter().enumerate() {
+        counter.add(i as u32 + 1);
+    }
+    drop(counters);
+    assert_eq!(total, 6);
+}
+
+#[test]
+fn test_non_copy() {
+    let mut value = String::from("a");
+    let a = AliasableCell::from_unique(&mut value);
+    let b = a;
+    assert_eq!(a.replace(String::from("b")), "a");
+    assert_eq!(b.take(), "b");
+    b.set(String::from("c"));
+
+    let mut other = String::from("d");
+    let c = AliasableCell::from_unique(&mut other);
+    a.swap(c);
+    a.swap(b);
+    assert_eq!(c.take(), "c");
+    assert_eq!(value, "d");
+}
+
+#[test]
+fn test_mut_as_cell() {
+    let mut value = 1;
+    let mut aliasable = AliasableMut::from_unique(&mut value);
+    {
+        let cell = aliasable.as_cell();
+        let copy = cell;
+        cell.set(2);
+        assert_eq!(copy.get(), 2);
+        assert_eq!(cell.as_ptr(), copy.as_ptr());
+    }
+    assert_eq!(*aliasable, 2);
+    *aliasable += 1;
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_aliased_mut_as_cell() {
+    let mut value = 1;
+    let mut a = AliasableMut::from_unique(&mut value);
+    // SAFETY: Both copies are only accessed through cells, which never create
+    // references to the location.
+    let mut b = unsafe { core::ptr::read(&a) };
+    let cell_a = a.as_cell();
+    let cell_b = b.as_cell();
+    cell_a.set(2);
+    assert_eq!(cell_b.get(), 2);
+    cell_b.set(3);
+    assert_eq!(cell_a.get(), 3);
+    assert_eq!(cell_a, cell_b);
+}
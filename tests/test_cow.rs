@@ -0,0 +1,104 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aliasable::prelude::{AliasableBox, AliasableCow, AliasableString, AliasableVec};
+use std::borrow::Cow;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_borrowed() {
+    let s = String::from("hello");
+    let cow = AliasableCow::from(s.as_str());
+    assert!(cow.is_borrowed());
+    assert!(!cow.is_owned());
+    assert_eq!(cow.as_ptr(), s.as_ptr());
+    assert_eq!(cow.into_owned(), "hello");
+}
+
+#[test]
+fn test_to_mut() {
+    let mut cow = AliasableCow::<str>::Borrowed("hello");
+    let owned: &mut AliasableString = cow.to_mut();
+    owned.make_ascii_uppercase();
+    let ptr = owned.as_ptr();
+    assert!(cow.is_owned());
+    assert_eq!(&*cow, "HELLO");
+    // Promoting an already owned value keeps the same allocation.
+    assert_eq!(cow.to_mut().as_ptr(), ptr);
+    let owned: String = cow.into_owned();
+    assert_eq!(owned.as_ptr(), ptr);
+}
+
+#[test]
+fn test_slice() {
+    let mut cow = AliasableCow::<[u8]>::Borrowed(&[1, 2, 3]);
+    let owned: &mut AliasableVec<u8> = cow.to_mut();
+    owned[0] = 10;
+    assert_eq!(&*cow, [10, 2, 3]);
+    assert_eq!(cow.into_owned(), vec![10, 2, 3]);
+}
+
+#[test]
+fn test_sized() {
+    let mut cow = AliasableCow::Borrowed(&5);
+    let owned: &mut AliasableBox<i32> = cow.to_mut();
+    **owned += 1;
+    assert_eq!(*cow, 6);
+    assert_eq!(cow.into_owned(), 6);
+}
+
+#[test]
+fn test_unique() {
+    let cow = AliasableCow::from(Cow::<str>::Owned(String::from("owned")));
+    assert!(cow.is_owned());
+    let ptr = cow.as_ptr();
+    let cow = AliasableCow::into_unique(cow);
+    assert!(matches!(cow, Cow::Owned(ref s) if s.as_ptr() == ptr));
+    let cow: Cow<'_, str> = AliasableCow::from_unique(Cow::Borrowed("borrowed")).into();
+    assert!(matches!(cow, Cow::Borrowed("borrowed")));
+}
+
+#[test]
+fn test_clone() {
+    let cow = AliasableCow::<str>::Owned(AliasableString::from(String::from("a")));
+    let clone = cow.clone();
+    assert_eq!(clone, cow);
+    assert_ne!(clone.as_ptr(), cow.as_ptr());
+    let borrowed = AliasableCow::<str>::Borrowed("b");
+    assert!(borrowed.clone().is_borrowed());
+}
+
+#[test]
+fn test_fmt() {
+    let cow = AliasableCow::<str>::Borrowed("hi");
+    assert_eq!(format!("{:?}", cow), "\"hi\"");
+    assert_eq!(format!("{}", cow), "hi");
+    assert_eq!(&*AliasableCow::<str>::default(), "");
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(
+        AliasableCow::<str>::Borrowed("a"),
+        AliasableCow::Owned(AliasableString::from(String::from("b"))),
+    );
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(hash_of(AliasableCow::<str>::Borrowed("a")), hash_of("a"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_path() {
+    use std::path::{Path, PathBuf};
+
+    let mut cow = AliasableCow::from(Path::new("/tmp"));
+    assert!(cow.is_borrowed());
+    let _ = cow.to_mut();
+    assert!(cow.is_owned());
+    assert_eq!(cow.into_owned(), PathBuf::from("/tmp"));
+}
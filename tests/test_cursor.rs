@@ -0,0 +1,111 @@
+#![cfg(feature = "alloc")]
+
+use aliasable::prelude::{AliasableBox, AliasableVec, Cursor};
+
+#[test]
+fn test_position() {
+    let mut cursor = Cursor::new(AliasableVec::from(vec![1u8, 2, 3]));
+    assert_eq!(cursor.position(), 0);
+    assert_eq!(cursor.remaining_slice(), [1, 2, 3]);
+    cursor.set_position(2);
+    assert_eq!(cursor.remaining_slice(), [3]);
+    cursor.set_position(10);
+    assert!(cursor.remaining_slice().is_empty());
+    cursor.get_mut()[0] = 10;
+    assert_eq!(&**cursor.get_ref(), [10, 2, 3]);
+    assert_eq!(&*cursor.into_inner(), [10, 2, 3]);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_buf() {
+    use bytes::Buf;
+
+    let aliasable = AliasableBox::from_unique(vec![1u8, 2, 3, 4].into_boxed_slice());
+    let ptr = aliasable.as_ptr();
+    let mut cursor = Cursor::new(aliasable);
+    assert_eq!(cursor.remaining(), 4);
+    assert_eq!(cursor.chunk().as_ptr(), ptr);
+    assert_eq!(cursor.get_u16(), 0x0102);
+    assert_eq!(cursor.position(), 2);
+    assert_eq!(cursor.chunk(), [3, 4]);
+    cursor.advance(2);
+    assert!(!cursor.has_remaining());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+#[should_panic(expected = "cannot advance past `remaining`")]
+fn test_buf_advance_past_end() {
+    use bytes::Buf;
+
+    Cursor::new(AliasableVec::from(vec![1u8])).advance(2);
+}
+
+#[test]
+fn test_box() {
+    let cursor = Cursor::new(AliasableBox::from_unique(vec![1u8, 2].into_boxed_slice()));
+    assert_eq!(cursor.remaining_slice(), [1, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read() {
+    use std::io::{BufRead, Read};
+
+    let mut cursor = Cursor::new(AliasableVec::from(b"hello\nworld".to_vec()));
+    let mut line = String::new();
+    let _ = cursor.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+    let mut rest = [0; 8];
+    assert_eq!(cursor.read(&mut rest).unwrap(), 5);
+    assert_eq!(&rest[..5], b"world");
+    assert_eq!(cursor.read(&mut rest).unwrap(), 0);
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn test_futures_read() {
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+    use futures_io::{AsyncBufRead, AsyncRead};
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut cursor = Cursor::new(AliasableVec::from(b"abc".to_vec()));
+    let mut buf = [0; 2];
+    assert!(matches!(
+        Pin::new(&mut cursor).poll_read(&mut cx, &mut buf),
+        Poll::Ready(Ok(2))
+    ));
+    assert_eq!(&buf, b"ab");
+    match Pin::new(&mut cursor).poll_fill_buf(&mut cx) {
+        Poll::Ready(Ok(rest)) => assert_eq!(rest, b"c"),
+        _ => panic!("expected ready"),
+    }
+    Pin::new(&mut cursor).consume(1);
+    assert_eq!(cursor.position(), 3);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_read() {
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+    use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut cursor = Cursor::new(AliasableVec::from(b"abc".to_vec()));
+    let mut storage = [0; 2];
+    let mut buf = ReadBuf::new(&mut storage);
+    assert!(matches!(
+        Pin::new(&mut cursor).poll_read(&mut cx, &mut buf),
+        Poll::Ready(Ok(()))
+    ));
+    assert_eq!(buf.filled(), b"ab");
+    match Pin::new(&mut cursor).poll_fill_buf(&mut cx) {
+        Poll::Ready(Ok(rest)) => assert_eq!(rest, b"c"),
+        _ => panic!("expected ready"),
+    }
+    Pin::new(&mut cursor).consume(1);
+    assert_eq!(cursor.position(), 3);
+}
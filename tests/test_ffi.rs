@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use aliasable::prelude::{AliasableCString, AliasableOsString, UniqueCString, UniqueOsString};
+use core::pin::Pin;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_c_string_new() {
+    let aliasable = AliasableCString::from_unique(UniqueCString::new("hello").unwrap());
+    assert_eq!(aliasable.to_bytes(), b"hello");
+    let unique = AliasableCString::into_unique(aliasable);
+    assert_eq!(unique.as_bytes_with_nul(), b"hello\0");
+}
+
+#[test]
+fn test_c_string_new_pin() {
+    let unique = UniqueCString::new("hello").unwrap();
+    let aliasable = AliasableCString::from_unique_pin(Pin::new(unique));
+    assert_eq!(aliasable.to_bytes(), b"hello");
+    let unique = AliasableCString::into_unique_pin(aliasable);
+    assert_eq!(unique.to_bytes(), b"hello");
+}
+
+#[test]
+fn test_c_string_refs() {
+    let aliasable = AliasableCString::from_unique(UniqueCString::new("hello").unwrap());
+    let ptr = aliasable.as_ptr();
+    assert_eq!(ptr, aliasable.as_c_str().as_ptr());
+    assert_eq!(ptr, aliasable.as_ref().as_ptr());
+    // Moving the aliasable string does not move the allocation.
+    let moved = aliasable;
+    assert_eq!(ptr, moved.as_ptr());
+}
+
+#[test]
+fn test_c_string_debug() {
+    let aliasable = AliasableCString::from_unique(UniqueCString::new("hello").unwrap());
+    assert_eq!(format!("{:?}", aliasable), "\"hello\"");
+}
+
+#[test]
+fn test_c_string_into_bytes_with_nul() {
+    let aliasable = AliasableCString::from_unique(UniqueCString::new("hi").unwrap());
+    assert_eq!(&*aliasable.into_bytes_with_nul(), b"hi\0");
+}
+
+#[test]
+fn test_c_string_default() {
+    assert_eq!(AliasableCString::default().to_bytes_with_nul(), b"\0");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_c_string_clone() {
+    let mut s = AliasableCString::from_unique(UniqueCString::new("hello").unwrap());
+    assert_eq!(s.clone().to_bytes(), b"hello");
+    s.clone_from(&AliasableCString::from_unique(
+        UniqueCString::new("hi").unwrap(),
+    ));
+    assert_eq!(s.to_bytes(), b"hi");
+}
+
+#[test]
+fn test_c_string_cmp() {
+    check_ordering(
+        AliasableCString::from_unique(UniqueCString::new("abc").unwrap()),
+        AliasableCString::from_unique(UniqueCString::new("abd").unwrap()),
+    );
+}
+
+#[test]
+fn test_c_string_hash() {
+    let unique = UniqueCString::new("some data").unwrap();
+    assert_eq!(
+        hash_of(AliasableCString::from_unique(unique.clone())),
+        hash_of(unique)
+    );
+}
+
+#[test]
+fn test_os_string_new() {
+    let aliasable = AliasableOsString::from_unique(UniqueOsString::from("hello"));
+    assert_eq!(aliasable.as_os_str(), "hello");
+    let unique = AliasableOsString::into_unique(aliasable);
+    assert_eq!(unique, "hello");
+}
+
+#[test]
+fn test_os_string_new_pin() {
+    let aliasable = AliasableOsString::from_unique_pin(Pin::new(UniqueOsString::from("hello")));
+    assert_eq!(&*aliasable, "hello");
+    let unique = AliasableOsString::into_unique_pin(aliasable);
+    assert_eq!(&*unique, "hello");
+}
+
+#[test]
+fn test_os_string_debug() {
+    let aliasable = AliasableOsString::from_unique(UniqueOsString::from("hello"));
+    assert_eq!(format!("{:?}", aliasable), "\"hello\"");
+}
+
+#[test]
+fn test_os_string_into_encoded_bytes() {
+    let aliasable = AliasableOsString::from_unique(UniqueOsString::from("hi"));
+    assert_eq!(&*aliasable.into_encoded_bytes(), b"hi");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_os_string_clone() {
+    let mut s = AliasableOsString::from_unique("hello".into());
+    assert_eq!(&*s.clone(), "hello");
+    s.clone_from(&AliasableOsString::from_unique("world".into()));
+    assert_eq!(&*s, "world");
+}
+
+#[test]
+fn test_os_string_cmp() {
+    check_ordering(
+        AliasableOsString::from_unique("abcdef".into()),
+        AliasableOsString::from_unique("abdef".into()),
+    );
+}
+
+#[test]
+fn test_os_string_hash() {
+    assert_eq!(
+        hash_of(AliasableOsString::from_unique("some data".into())),
+        hash_of(UniqueOsString::from("some data"))
+    );
+}
@@ -0,0 +1,97 @@
+#![cfg(all(feature = "alloc", feature = "hashbrown"))]
+
+use aliasable::hash_map::Entry;
+use aliasable::prelude::{AliasableHashMap, UniqueHashMap};
+
+#[test]
+fn test_new() {
+    let unique: UniqueHashMap<_, _> = vec![(1, "a"), (2, "b")].into_iter().collect();
+    let aliasable = AliasableHashMap::from_unique(unique.clone());
+    assert_eq!(aliasable.len(), 2);
+    assert_eq!(aliasable[&1], "a");
+    assert_eq!(AliasableHashMap::into_unique(aliasable), unique);
+}
+
+#[test]
+fn test_insert_get_remove() {
+    let mut map = AliasableHashMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert("a", 1), None);
+    assert_eq!(map.insert("b", 2), None);
+    assert_eq!(map.insert("a", 3), Some(1));
+    assert_eq!(map.get("a"), Some(&3));
+    assert!(map.contains_key("b"));
+    *map.get_mut("b").unwrap() += 10;
+    assert_eq!(map.remove("b"), Some(12));
+    assert_eq!(map.remove("b"), None);
+    assert_eq!(map.len(), 1);
+    map.clear();
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_stable_addresses() {
+    let mut map = AliasableHashMap::new();
+    let _ = map.insert(0, 0);
+    let ptr = map.get_ptr(&0).unwrap();
+    // Force the underlying table to rehash and move its entries around.
+    for i in 1..1000 {
+        let _ = map.insert(i, i);
+    }
+    let _ = map.insert(0, 42);
+    assert_eq!(map.get(&0).unwrap() as *const i32, ptr);
+    // SAFETY: The value has not been moved or dropped.
+    assert_eq!(unsafe { *ptr }, 42);
+}
+
+#[test]
+fn test_entry() {
+    let mut map = AliasableHashMap::new();
+    *map.entry("a").or_insert(1) += 1;
+    *map.entry("a").or_default() += 1;
+    map.entry("b").and_modify(|v| *v = 100).or_insert_with(|| 5);
+    let _ = map.entry("b").and_modify(|v| *v += 1);
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 6);
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"a");
+            assert_eq!(entry.insert(7), 3);
+            assert_eq!(entry.remove(), 7);
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry("c") {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), "c"),
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_iter() {
+    let mut map: AliasableHashMap<_, _> = vec![(2, 20), (1, 10)].into_iter().collect();
+    for (_, v) in &mut map {
+        *v += 1;
+    }
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort();
+    assert_eq!(entries, [(&1, &11), (&2, &21)]);
+}
+
+#[test]
+fn test_debug() {
+    let map: AliasableHashMap<_, _> = vec![(1, "a")].into_iter().collect();
+    assert_eq!(format!("{:?}", map), "{1: \"a\"}");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let map: AliasableHashMap<_, _> = vec![(1, "a")].into_iter().collect();
+    assert_eq!(map.clone(), map);
+    assert_eq!(
+        <AliasableHashMap<i32, i32>>::default(),
+        AliasableHashMap::new()
+    );
+}
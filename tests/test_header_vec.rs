@@ -0,0 +1,149 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aliasable::prelude::AliasableHeaderVec;
+use std::cell::Cell;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_new() {
+    let mut aliasable = AliasableHeaderVec::new("packet");
+    assert!(aliasable.is_empty());
+    assert_eq!(aliasable.slice(), &[] as &[u8]);
+    aliasable.extend_from_slice(b"payload");
+    *aliasable.header_mut() = "header";
+    assert_eq!(*aliasable.header(), "header");
+    assert_eq!(aliasable.slice(), b"payload");
+    aliasable.slice_mut()[0] = b'P';
+    let (header, tail) = AliasableHeaderVec::into_parts(aliasable);
+    assert_eq!(header, "header");
+    assert_eq!(tail, b"Payload");
+}
+
+#[test]
+fn test_from_parts() {
+    let aliasable = AliasableHeaderVec::from_parts(1u8, vec![2u64, 3]);
+    assert_eq!(aliasable.capacity(), 2);
+    assert_eq!(*aliasable.header(), 1);
+    assert_eq!(aliasable.slice(), [2, 3]);
+}
+
+#[test]
+fn test_push_pop() {
+    let mut aliasable = AliasableHeaderVec::with_capacity(0u8, 1);
+    assert_eq!(aliasable.pop(), None);
+    for i in 0..100u64 {
+        aliasable.push(i);
+    }
+    assert_eq!(aliasable.len(), 100);
+    assert!(aliasable.capacity() >= 100);
+    assert_eq!(aliasable.pop(), Some(99));
+    aliasable.truncate(10);
+    assert_eq!(aliasable.slice(), (0..10).collect::<Vec<_>>());
+    aliasable.clear();
+    assert!(aliasable.is_empty());
+    assert_eq!(*aliasable.header(), 0);
+}
+
+#[test]
+fn test_ptrs() {
+    let mut aliasable = AliasableHeaderVec::with_capacity(7u16, 4);
+    aliasable.push(1u32);
+    let header_ptr = aliasable.as_header_ptr();
+    let tail_ptr = aliasable.as_ptr();
+    assert_eq!(
+        header_ptr.cast::<u8>(),
+        aliasable.as_mut_header_ptr().cast()
+    );
+    assert_eq!(tail_ptr, aliasable.as_mut_ptr());
+    assert_eq!(tail_ptr as usize - header_ptr as usize, 4);
+    // Pushing within capacity never moves the allocation.
+    aliasable.push(2);
+    aliasable.push(3);
+    assert_eq!(aliasable.as_header_ptr(), header_ptr);
+    assert_eq!(aliasable.as_ptr(), tail_ptr);
+    // SAFETY: Nothing else is accessing the header or tail.
+    unsafe {
+        *header_ptr.cast_mut() += 1;
+        *tail_ptr.cast_mut() += 10;
+    }
+    assert_eq!(*aliasable.header(), 8);
+    assert_eq!(aliasable.slice(), [11, 2, 3]);
+}
+
+#[test]
+fn test_zst() {
+    let mut aliasable = AliasableHeaderVec::new(());
+    assert_eq!(aliasable.capacity(), usize::MAX);
+    aliasable.extend(vec![(); 3]);
+    assert_eq!(aliasable.len(), 3);
+    let mut aliasable = AliasableHeaderVec::new(());
+    aliasable.push(1u8);
+    assert_eq!(aliasable.slice(), [1]);
+}
+
+#[test]
+fn test_drop() {
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut aliasable = AliasableHeaderVec::new(Counted(&drops));
+    aliasable.extend((0..3).map(|_| Counted(&drops)));
+    aliasable.truncate(1);
+    assert_eq!(drops.get(), 2);
+    drop(aliasable);
+    assert_eq!(drops.get(), 4);
+    let (header, tail) = AliasableHeaderVec::into_parts(AliasableHeaderVec::from_parts(
+        Counted(&drops),
+        vec![Counted(&drops)],
+    ));
+    assert_eq!(drops.get(), 4);
+    drop((header, tail));
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_debug() {
+    let aliasable = AliasableHeaderVec::from_parts(1, vec![2, 3]);
+    assert_eq!(
+        format!("{:?}", aliasable),
+        "AliasableHeaderVec { header: 1, slice: [2, 3] }"
+    );
+}
+
+#[test]
+fn test_clone() {
+    let aliasable = AliasableHeaderVec::from_parts(String::from("a"), vec![String::from("b")]);
+    assert_eq!(aliasable.clone(), aliasable);
+    assert_eq!(
+        AliasableHeaderVec::<u8, u8>::default(),
+        AliasableHeaderVec::new(0)
+    );
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(
+        AliasableHeaderVec::from_parts(1, vec![5]),
+        AliasableHeaderVec::from_parts(1, vec![7]),
+    );
+    check_ordering(
+        AliasableHeaderVec::from_parts(1, vec![7]),
+        AliasableHeaderVec::from_parts(2, vec![5]),
+    );
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(
+        hash_of(AliasableHeaderVec::from_parts(1, vec![5])),
+        hash_of((1, [5]))
+    );
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "alloc")]
+
+use aliasable::prelude::{IntrusiveList, ListNode};
+use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+fn list_of(values: &[i32]) -> IntrusiveList<i32> {
+    let mut list = IntrusiveList::new();
+    for &value in values {
+        let _ = list.push_back(ListNode::new(value));
+    }
+    list
+}
+
+fn values(list: &IntrusiveList<i32>) -> Vec<i32> {
+    list.iter().copied().collect()
+}
+
+#[test]
+fn test_push_pop() {
+    let mut list = IntrusiveList::new();
+    assert!(list.is_empty());
+    let _ = list.push_back(ListNode::new(2));
+    let _ = list.push_front(ListNode::new(1));
+    let _ = list.push_back(ListNode::new(3));
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(list.back(), Some(&3));
+    assert_eq!(values(&list), [1, 2, 3]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+    let front = list.pop_front().unwrap();
+    assert_eq!(*front.value(), 1);
+    let back = list.pop_back().unwrap();
+    assert_eq!(ListNode::into_inner(back), 3);
+    assert_eq!(values(&list), [2]);
+    list.clear();
+    assert!(list.pop_front().is_none());
+    assert!(list.pop_back().is_none());
+}
+
+#[test]
+fn test_remove_by_handle() {
+    let mut list = IntrusiveList::new();
+    let handles: Vec<_> = (0..4).map(|i| list.push_back(ListNode::new(i))).collect();
+
+    // SAFETY: The nodes of the handles are in the list.
+    let node = unsafe { list.remove(handles[1]) };
+    assert_eq!(*node.value(), 1);
+    assert_eq!(values(&list), [0, 2, 3]);
+
+    // Reinserting the node at the front, as an LRU cache would, keeps its
+    // handle.
+    assert_eq!(list.push_front(node), handles[1]);
+    assert_eq!(values(&list), [1, 0, 2, 3]);
+
+    // SAFETY: The nodes of the handles are in the list.
+    let mut cursor = unsafe { list.cursor_from_handle(handles[3]) };
+    *cursor.current().unwrap() += 10;
+    cursor.move_prev();
+    assert_eq!(cursor.handle(), Some(handles[2]));
+    assert_eq!(values(&list), [1, 0, 2, 13]);
+
+    for handle in handles {
+        // SAFETY: Each node is still in the list.
+        drop(unsafe { list.remove(handle) });
+    }
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_cursor() {
+    let mut list = list_of(&[1, 3]);
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.move_next();
+    let _ = cursor.insert_before(ListNode::new(2));
+    let _ = cursor.insert_after(ListNode::new(4));
+    assert_eq!(cursor.current(), Some(&mut 3));
+
+    // Walk through the ghost position from the back to the front.
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.handle(), None);
+    let _ = cursor.insert_before(ListNode::new(5));
+    let _ = cursor.insert_after(ListNode::new(0));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 0));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&mut 5));
+    assert_eq!(values(&list), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_remove_current() {
+    let mut list = list_of(&[1, 2, 3]);
+    let mut cursor = list.cursor_back_mut();
+    cursor.move_prev();
+    let node = cursor.remove_current().unwrap();
+    assert_eq!(ListNode::into_inner(node), 2);
+    assert_eq!(cursor.current(), Some(&mut 3));
+    let _ = cursor.remove_current().unwrap();
+    assert_eq!(cursor.current(), None);
+    assert!(cursor.remove_current().is_none());
+    cursor.move_next();
+    let _ = cursor.remove_current().unwrap();
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_splice() {
+    let mut list = list_of(&[1, 4]);
+    let mut cursor = list.cursor_back_mut();
+    cursor.splice(list_of(&[2, 3]));
+    cursor.splice(IntrusiveList::new());
+    cursor.move_next();
+    cursor.splice(list_of(&[5, 6]));
+    cursor.move_next();
+    cursor.splice(list_of(&[0]));
+    assert_eq!(list.len(), 7);
+    assert_eq!(values(&list), [0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [6, 5, 4, 3, 2, 1, 0]
+    );
+
+    let mut empty = IntrusiveList::new();
+    empty.cursor_front_mut().splice(list_of(&[1, 2]));
+    assert_eq!(values(&empty), [1, 2]);
+    assert_eq!(empty.back(), Some(&2));
+}
+
+#[test]
+fn test_node_values() {
+    let mut node = ListNode::new(String::from("a"));
+    node.as_mut().value_mut().push('b');
+    let mut list = IntrusiveList::new();
+    let _ = list.push_back(node);
+    let _ = list.push_back(ListNode::new(String::from("c")));
+    assert_eq!(format!("{:?}", list), r#"["ab", "c"]"#);
+    let node = list.pop_front().unwrap();
+    assert_eq!(format!("{:?}", node), r#""ab""#);
+    assert_eq!(list.iter().len(), 1);
+}
+
+#[test]
+fn test_drop() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut list = IntrusiveList::new();
+    for _ in 0..3 {
+        let _ = list.push_back(ListNode::new(Counted));
+    }
+    drop(list.pop_front());
+    assert_eq!(DROPS.load(SeqCst), 1);
+    drop(list);
+    assert_eq!(DROPS.load(SeqCst), 3);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "alloc")]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec, IoBuf, IoBufMut};
+
+fn submit<B: IoBufMut>(mut buf: B, data: &[u8]) -> B {
+    assert!(data.len() <= buf.capacity());
+    // SAFETY: The buffer has room for `data`, which initializes it.
+    unsafe {
+        core::ptr::copy_nonoverlapping(data.as_ptr(), buf.as_mut_ptr(), data.len());
+        buf.set_len(data.len());
+    }
+    buf
+}
+
+#[test]
+fn test_vec() {
+    let buf = AliasableVec::from_unique(Vec::with_capacity(8));
+    let ptr = IoBuf::as_ptr(&buf);
+    assert!(IoBuf::is_empty(&buf));
+    assert_eq!(IoBuf::capacity(&buf), 8);
+    // Moving the buffer into and out of the operation never moves its memory.
+    let buf = submit(buf, b"hello");
+    assert_eq!(IoBuf::as_ptr(&buf), ptr);
+    assert_eq!(IoBuf::len(&buf), 5);
+    assert_eq!(&*buf, b"hello");
+}
+
+#[test]
+fn test_box() {
+    let buf = AliasableBox::from_unique(vec![0u8; 4].into_boxed_slice());
+    let ptr = IoBuf::as_ptr(&buf);
+    assert_eq!(IoBuf::len(&buf), 4);
+    assert_eq!(IoBuf::capacity(&buf), 4);
+    let buf = submit(buf, b"ab");
+    assert_eq!(IoBuf::as_ptr(&buf), ptr);
+    assert_eq!(&*buf, b"ab\0\0");
+}
+
+#[test]
+fn test_string() {
+    let buf = AliasableString::from_unique(String::from("hi"));
+    assert_eq!(IoBuf::as_ptr(&buf), buf.as_ptr());
+    assert_eq!(IoBuf::len(&buf), 2);
+    assert!(IoBuf::capacity(&buf) >= 2);
+}
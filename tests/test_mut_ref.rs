@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 mod common;
 
 use aliasable::prelude::{AliasableMut, UniqueBox};
@@ -24,6 +26,17 @@ fn test_new_pin() {
     assert_eq!(**unique, 10);
 }
 
+#[test]
+fn test_const_from_unique() {
+    const fn wrap(value: &mut i32) -> AliasableMut<'_, i32> {
+        AliasableMut::from_unique(value)
+    }
+
+    let mut data = 10;
+    let aliasable = wrap(&mut data);
+    assert_eq!(*aliasable, 10);
+}
+
 #[test]
 fn test_refs() {
     let mut data = UniqueBox::new(10);
@@ -54,3 +67,54 @@ fn test_cmp() {
 fn test_hash() {
     assert_eq!(hash_of(AliasableMut::from_unique(&mut 389)), hash_of(389));
 }
+
+#[test]
+fn test_cmp_foreign() {
+    let mut data = 5;
+    let aliasable = AliasableMut::from_unique(&mut data);
+    assert_eq!(aliasable, &mut 5);
+    assert!(aliasable < &mut 6);
+}
+
+#[test]
+fn test_borrow() {
+    use core::borrow::Borrow;
+
+    let mut data = 5;
+    let aliasable = AliasableMut::from_unique(&mut data);
+    let borrowed: &i32 = aliasable.borrow();
+    assert_eq!(*borrowed, 5);
+}
+
+#[test]
+fn test_display() {
+    let mut data = 10;
+    let aliasable = AliasableMut::from_unique(&mut data);
+    assert_eq!(format!("{:03}", aliasable), "010");
+}
+
+#[test]
+fn test_pointer() {
+    let mut data = 10;
+    let ptr: *const i32 = &data;
+    let aliasable = AliasableMut::from_unique(&mut data);
+    assert_eq!(format!("{:p}", aliasable), format!("{:p}", ptr));
+}
+
+#[cfg(feature = "unsize")]
+#[test]
+fn test_unsize() {
+    use core::fmt::Debug;
+    use unsize::{CoerceUnsize, Coercion};
+
+    let mut data = [1u8, 2];
+    let aliasable = AliasableMut::from_unique(&mut data);
+    let mut unsized_mut: AliasableMut<'_, [u8]> = aliasable.unsize(Coercion::to_slice());
+    unsized_mut[0] = 3;
+    assert_eq!(*unsized_mut, [3, 2]);
+
+    let mut data = 10;
+    let aliasable = AliasableMut::from_unique(&mut data);
+    let dyn_mut: AliasableMut<'_, dyn Debug> = aliasable.unsize(Coercion::to_debug());
+    assert_eq!(format!("{:?}", dyn_mut), "10");
+}
@@ -0,0 +1,38 @@
+#![cfg(aliasable_nightly)]
+#![feature(arbitrary_self_types)]
+
+use aliasable::prelude::{AliasableBox, UniqueBox};
+use core::fmt::Debug;
+
+trait Shape {
+    fn area(self: AliasableBox<Self>) -> u32;
+}
+
+struct Square(u32);
+
+impl Shape for Square {
+    fn area(self: AliasableBox<Self>) -> u32 {
+        self.0 * self.0
+    }
+}
+
+#[test]
+fn test_coerce_unsized() {
+    let aliasable: AliasableBox<dyn Debug> = AliasableBox::from_unique(UniqueBox::new(10));
+    assert_eq!(format!("{:?}", aliasable), "10");
+    let slice: AliasableBox<[u8]> = AliasableBox::from_unique(UniqueBox::new([1u8, 2]));
+    assert_eq!(*slice, [1, 2]);
+}
+
+#[test]
+fn test_coerce_unsized_pin() {
+    use core::pin::Pin;
+    let aliasable: Pin<AliasableBox<dyn Debug>> = AliasableBox::from_unique_pin(UniqueBox::pin(10));
+    assert_eq!(format!("{:?}", aliasable), "10");
+}
+
+#[test]
+fn test_dispatch_from_dyn() {
+    let shape: AliasableBox<dyn Shape> = AliasableBox::from_unique(UniqueBox::new(Square(3)));
+    assert_eq!(shape.area(), 9);
+}
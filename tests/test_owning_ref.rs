@@ -0,0 +1,91 @@
+#![cfg(all(
+    feature = "alloc",
+    feature = "stable_deref_trait",
+    feature = "aliasable_deref_trait"
+))]
+
+use aliasable::prelude::{
+    AliasableBox, AliasableMut, AliasableString, AliasableVec, OwningRef, OwningRefMut,
+};
+
+#[test]
+fn test_map() {
+    let owner = AliasableVec::from_unique(vec![1, 2, 3]);
+    let first = OwningRef::new(owner).map(|slice| &slice[0]);
+    // Moving the owner along with the reference keeps it valid.
+    let moved = Box::new(first);
+    assert_eq!(**moved, 1);
+    assert_eq!(moved.as_owner().len(), 3);
+    assert_eq!(moved.into_owner(), [1, 2, 3]);
+}
+
+#[test]
+fn test_try_map() {
+    let owner = AliasableString::from("key=value");
+    let value = OwningRef::new(owner)
+        .try_map(|s| s.split_once('=').map(|(_, v)| v).ok_or(()))
+        .unwrap();
+    assert_eq!(&*value, "value");
+
+    let owner = AliasableString::from("novalue");
+    let err = OwningRef::new(owner).try_map(|s| s.split_once('=').map(|(_, v)| v).ok_or(s.len()));
+    assert_eq!(err.unwrap_err(), 7);
+}
+
+#[test]
+fn test_map_with_owner() {
+    let owner = AliasableBox::from_unique(Box::new((1, 2)));
+    let second = OwningRef::new(owner)
+        .map(|pair| &pair.0)
+        .map_with_owner(|owner, _| &owner.1);
+    assert_eq!(*second, 2);
+}
+
+#[test]
+fn test_map_with_owner_target() {
+    // The closure only sees the target of the owner, so returning it can't
+    // leave a reference to the owner which is about to move.
+    let owner = AliasableBox::from_unique(Box::new(5u32));
+    let target = OwningRef::new(owner).map_with_owner(|owner, _| owner);
+    let moved = Box::new(target);
+    assert_eq!(**moved, 5);
+}
+
+#[test]
+fn test_mut() {
+    let owner = AliasableVec::from_unique(vec![1, 2, 3]);
+    let mut last = OwningRefMut::new(owner).map(|slice| &mut slice[2]);
+    *last = 30;
+    let mut moved = Box::new(last);
+    **moved += 1;
+    let shared = moved.into_ref();
+    assert_eq!(*shared, 31);
+    assert_eq!(shared.into_owner(), [1, 2, 31]);
+}
+
+#[test]
+fn test_mut_try_map() {
+    let owner = AliasableVec::from_unique(vec![1, 2]);
+    let second = OwningRefMut::new(owner).try_map(|slice| slice.get_mut(1).ok_or(()));
+    assert_eq!(*second.unwrap(), 2);
+
+    let owner = AliasableVec::from_unique(vec![1, 2]);
+    let missing = OwningRefMut::new(owner).try_map(|slice| slice.get_mut(5).ok_or(()));
+    assert!(missing.is_err());
+}
+
+#[test]
+fn test_aliasable_mut_owner() {
+    let mut data = [1, 2];
+    let owner = AliasableMut::from_unique(&mut data);
+    let mut first = OwningRefMut::new(owner).map(|array| &mut array[0]);
+    *first = 10;
+    let _ = first.into_owner();
+    assert_eq!(data, [10, 2]);
+}
+
+#[test]
+fn test_debug() {
+    let owner = AliasableBox::from_unique(Box::new(5));
+    assert_eq!(format!("{:?}", OwningRef::new(owner)), "5");
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use aliasable::prelude::{AliasablePathBuf, UniquePathBuf};
+use core::pin::Pin;
+use std::path::Path;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_new() {
+    let aliasable = AliasablePathBuf::from_unique(UniquePathBuf::from("a/b"));
+    assert_eq!(aliasable.as_path(), Path::new("a/b"));
+    let unique = AliasablePathBuf::into_unique(aliasable);
+    assert_eq!(unique, Path::new("a/b"));
+}
+
+#[test]
+fn test_new_pin() {
+    let aliasable = AliasablePathBuf::from_unique_pin(Pin::new(UniquePathBuf::from("a/b")));
+    assert_eq!(&*aliasable, Path::new("a/b"));
+    let unique = AliasablePathBuf::into_unique_pin(aliasable);
+    assert_eq!(&*unique, Path::new("a/b"));
+}
+
+#[test]
+fn test_refs() {
+    let aliasable = AliasablePathBuf::from_unique(UniquePathBuf::from("a/b"));
+    let ptr: *const Path = &*aliasable;
+    let as_ref_ptr: *const Path = aliasable.as_ref();
+    assert_eq!(ptr, as_ref_ptr);
+}
+
+#[test]
+fn test_debug() {
+    let aliasable = AliasablePathBuf::from_unique(UniquePathBuf::from("a/b"));
+    assert_eq!(format!("{:?}", aliasable), "\"a/b\"");
+}
+
+#[test]
+fn test_into_os_string() {
+    let aliasable = AliasablePathBuf::from_unique(UniquePathBuf::from("a/b"));
+    assert_eq!(&*aliasable.into_os_string(), "a/b");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let mut p = AliasablePathBuf::from_unique("a/b".into());
+    assert_eq!(&*p.clone(), Path::new("a/b"));
+    p.clone_from(&AliasablePathBuf::from_unique("c".into()));
+    assert_eq!(&*p, Path::new("c"));
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(
+        AliasablePathBuf::from_unique("a/b".into()),
+        AliasablePathBuf::from_unique("a/c".into()),
+    );
+    // Paths compare by components rather than bytes.
+    assert_eq!(
+        AliasablePathBuf::from_unique("a/b".into()),
+        AliasablePathBuf::from_unique("a//b/".into())
+    );
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(
+        hash_of(AliasablePathBuf::from_unique("a//b".into())),
+        hash_of(Path::new("a/b"))
+    );
+}
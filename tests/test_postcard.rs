@@ -0,0 +1,16 @@
+#![cfg(all(feature = "alloc", feature = "postcard"))]
+
+use aliasable::prelude::AliasableBox;
+use postcard::experimental::max_size::MaxSize;
+
+#[test]
+fn test_max_size() {
+    assert_eq!(
+        AliasableBox::<u32>::POSTCARD_MAX_SIZE,
+        u32::POSTCARD_MAX_SIZE
+    );
+    assert_eq!(
+        AliasableBox::<AliasableBox<[u8; 4]>>::POSTCARD_MAX_SIZE,
+        <[u8; 4]>::POSTCARD_MAX_SIZE
+    );
+}
@@ -0,0 +1,31 @@
+// Proptest persists failures to the filesystem, which Miri isolates.
+#![cfg(all(feature = "proptest", not(miri)))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_vec(aliasable in any::<AliasableVec<u8>>()) {
+        let clone = aliasable.clone();
+        let unique = AliasableVec::into_unique(aliasable);
+        prop_assert_eq!(&*clone, &*unique);
+    }
+
+    #[test]
+    fn test_vec_size(aliasable in any_with::<AliasableVec<u8>>(((2..5).into(), ()))) {
+        prop_assert!((2..5).contains(&aliasable.len()));
+    }
+
+    #[test]
+    fn test_box(aliasable in any::<AliasableBox<u32>>()) {
+        let value = *aliasable;
+        prop_assert_eq!(*AliasableBox::into_unique(aliasable), value);
+    }
+
+    #[test]
+    fn test_string(aliasable in any::<AliasableString>()) {
+        let clone = aliasable.clone();
+        prop_assert_eq!(&*clone, &*AliasableString::into_unique(aliasable));
+    }
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aliasable::prelude::{AliasableSmallVec, AliasableVec};
+use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+use self::common::{check_ordering, hash_of};
+
+type SmallVec<T> = AliasableSmallVec<[T; 4]>;
+
+#[test]
+fn test_inline() {
+    let mut v = SmallVec::new();
+    assert_eq!(v.capacity(), 4);
+    for i in 0..4 {
+        v.push(i);
+    }
+    assert!(!v.spilled());
+    assert_eq!(*v, [0, 1, 2, 3]);
+    assert_eq!(v.pop(), Some(3));
+    v.truncate(1);
+    assert_eq!(*v, [0]);
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(v.pop(), None);
+}
+
+#[test]
+fn test_spill() {
+    let mut v: SmallVec<String> = (0..4).map(|i| i.to_string()).collect();
+    assert!(!v.spilled());
+    v.push("4".into());
+    assert!(v.spilled());
+    assert!(v.capacity() >= 5);
+    assert_eq!(v.pop().as_deref(), Some("4"));
+    v.truncate(2);
+    assert_eq!(*v, ["0", "1"]);
+    assert!(v.spilled());
+}
+
+#[test]
+fn test_addresses() {
+    let mut v = SmallVec::new();
+    v.push(1);
+    v.spill();
+    assert!(v.spilled());
+    let ptr = v.as_ptr();
+    let moved = Box::new(v);
+    assert_eq!(moved.as_ptr(), ptr);
+    assert_eq!(**moved, [1]);
+
+    let big = SmallVec::<u8>::with_capacity(10);
+    assert!(big.spilled());
+    assert_eq!(big.capacity(), 10);
+    assert!(!SmallVec::<u8>::with_capacity(4).spilled());
+}
+
+#[test]
+fn test_inline_pointers() {
+    let mut v = SmallVec::new();
+    v.push(1);
+    let first = v.as_mut_ptr();
+    v.push(2);
+    assert_eq!(v.as_ptr(), first);
+    // SAFETY: The vec hasn't moved or spilled, and pushing doesn't invalidate
+    // pointers to its inline elements.
+    assert_eq!(unsafe { *first }, 1);
+    assert_eq!(v.pop(), Some(2));
+    // SAFETY: As above, and the first element is still initialized.
+    unsafe { *first = 10 };
+    v.push(3);
+    // SAFETY: As above.
+    assert_eq!(unsafe { *first }, 10);
+    assert_eq!(*v, [10, 3]);
+}
+
+#[test]
+fn test_vec_conversions() {
+    let vec = AliasableVec::from_unique(vec![1, 2]);
+    let ptr = vec.as_ptr();
+    let small = SmallVec::from(vec);
+    assert!(small.spilled());
+    assert_eq!(small.as_ptr(), ptr);
+    let vec = AliasableVec::from(small);
+    assert_eq!(vec.as_ptr(), ptr);
+
+    let small: SmallVec<_> = vec![1, 2, 3].into_iter().collect();
+    assert_eq!(small.into_vec(), [1, 2, 3]);
+}
+
+#[test]
+fn test_zst() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Zst;
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut v = SmallVec::new();
+    assert_eq!(v.capacity(), usize::MAX);
+    for _ in 0..10 {
+        v.push(Zst);
+    }
+    v.spill();
+    assert!(!v.spilled());
+    drop(v.pop());
+    assert_eq!(DROPS.load(SeqCst), 1);
+
+    let vec = v.into_vec();
+    assert_eq!(vec.len(), 9);
+    let v = SmallVec::from(vec);
+    assert!(!v.spilled());
+    assert_eq!(v.len(), 9);
+    drop(v);
+    assert_eq!(DROPS.load(SeqCst), 10);
+}
+
+#[test]
+fn test_drop() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let inline: SmallVec<_> = (0..3).map(|_| Counted).collect();
+    drop(inline);
+    assert_eq!(DROPS.load(SeqCst), 3);
+    let spilled: SmallVec<_> = (0..6).map(|_| Counted).collect();
+    drop(spilled);
+    assert_eq!(DROPS.load(SeqCst), 9);
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let v: SmallVec<_> = (0..6).collect();
+    assert_eq!(v.clone(), v);
+    let v: SmallVec<_> = (0..2).collect();
+    assert_eq!(v.clone(), v);
+}
+
+#[test]
+fn test_debug() {
+    let v: SmallVec<_> = (0..2).collect();
+    assert_eq!(format!("{:?}", v), "[0, 1]");
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(
+        vec![1, 2].into_iter().collect::<SmallVec<_>>(),
+        vec![1, 3].into_iter().collect::<SmallVec<_>>(),
+    );
+}
+
+#[test]
+fn test_hash() {
+    let v: SmallVec<_> = (0..2).collect();
+    assert_eq!(hash_of(v), hash_of([0, 1]));
+}
@@ -1,6 +1,8 @@
+#![cfg(feature = "alloc")]
+
 mod common;
 
-use aliasable::prelude::{AliasableString, AliasableVec, UniqueString};
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec, UniqueString};
 use core::pin::Pin;
 
 use self::common::{check_ordering, hash_of};
@@ -21,6 +23,16 @@ fn test_new_pin() {
     assert_eq!(&*unique, "hello");
 }
 
+#[test]
+fn test_const_new() {
+    static EMPTY: AliasableString = AliasableString::new();
+    assert_eq!(EMPTY, "");
+
+    let mut s = AliasableString::new();
+    s += "hello";
+    assert_eq!(s, "hello");
+}
+
 #[test]
 fn test_refs() {
     let mut aliasable = AliasableString::from_unique(UniqueString::from("hello"));
@@ -78,3 +90,70 @@ fn test_hash() {
         hash_of("some data")
     );
 }
+
+#[test]
+#[allow(clippy::cmp_owned)]
+fn test_cmp_foreign() {
+    let aliasable = AliasableString::from_unique("foo".into());
+    assert_eq!(aliasable, "foo");
+    assert_eq!(aliasable, *"foo");
+    assert_eq!(aliasable, UniqueString::from("foo"));
+    assert_eq!("foo", aliasable);
+    assert_eq!(UniqueString::from("foo"), aliasable);
+    assert_ne!(aliasable, "bar");
+    assert!(aliasable > "bar");
+    assert!("fop" > aliasable);
+    assert!(aliasable < UniqueString::from("fooo"));
+}
+
+#[test]
+fn test_borrow() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    let _ = map.insert(AliasableString::from_unique("key".into()), 1);
+    assert_eq!(map.get("key"), Some(&1));
+    assert_eq!(map.get("other"), None);
+}
+
+#[test]
+fn test_display() {
+    let aliasable = AliasableString::from_unique(UniqueString::from("hello"));
+    assert_eq!(format!("{:>7}", aliasable), "  hello");
+}
+
+#[test]
+fn test_conversions() {
+    use core::str::FromStr;
+
+    assert_eq!(AliasableString::from("abc"), "abc");
+    assert_eq!(AliasableString::from(&mut *String::from("abc")), "abc");
+    assert_eq!(AliasableString::from('é'), "é");
+    assert_eq!(AliasableString::from_str("abc").unwrap(), "abc");
+    assert_eq!("abc".parse::<AliasableString>().unwrap(), "abc");
+
+    let boxed = AliasableBox::<str>::from(AliasableString::from("abc"));
+    assert_eq!(&*boxed, "abc");
+    assert_eq!(AliasableString::from(boxed), "abc");
+}
+
+#[test]
+fn test_add() {
+    use core::fmt::Write;
+
+    let mut aliasable = AliasableString::from("foo") + "bar";
+    aliasable += "baz";
+    write!(aliasable, "{}", 1).unwrap();
+    assert_eq!(aliasable, "foobarbaz1");
+}
+
+#[test]
+fn test_index() {
+    let mut aliasable = AliasableString::from("hello");
+    assert_eq!(&aliasable[1..3], "el");
+    assert_eq!(&aliasable[..2], "he");
+    assert_eq!(&aliasable[3..], "lo");
+    aliasable[..].make_ascii_uppercase();
+    aliasable[1..].make_ascii_lowercase();
+    assert_eq!(aliasable, "Hello");
+}
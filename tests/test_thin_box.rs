@@ -0,0 +1,122 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aliasable::prelude::AliasableThinBox;
+use core::cell::Cell;
+use core::mem;
+
+use self::common::{check_ordering, hash_of};
+
+#[test]
+fn test_new() {
+    let mut aliasable = AliasableThinBox::new(10);
+    *aliasable += 1;
+    assert_eq!(*aliasable, 11);
+}
+
+#[test]
+fn test_size() {
+    assert_eq!(
+        mem::size_of::<AliasableThinBox<[u64]>>(),
+        mem::size_of::<usize>()
+    );
+    assert_eq!(
+        mem::size_of::<Option<AliasableThinBox<[u64]>>>(),
+        mem::size_of::<usize>()
+    );
+}
+
+#[test]
+fn test_refs() {
+    let mut aliasable = AliasableThinBox::new(10);
+    let ptr: *const u8 = &*aliasable;
+    let as_mut_ptr: *const u8 = aliasable.as_mut();
+    let as_ref_ptr: *const u8 = aliasable.as_ref();
+    assert_eq!(ptr, as_mut_ptr);
+    assert_eq!(ptr, as_ref_ptr);
+    assert_eq!(ptr, aliasable.as_ptr());
+    let moved = aliasable;
+    assert_eq!(ptr, moved.as_ptr());
+}
+
+#[test]
+fn test_slice() {
+    let mut aliasable = AliasableThinBox::<[String]>::from(vec!["a".to_owned(), "b".to_owned()]);
+    aliasable[1].push('!');
+    assert_eq!(&*aliasable, ["a", "b!"]);
+    let empty = AliasableThinBox::<[u8]>::from(Vec::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_align() {
+    #[repr(align(64))]
+    struct Aligned(u8);
+
+    let aliasable = AliasableThinBox::new(Aligned(1));
+    assert_eq!(aliasable.as_ptr() as usize % 64, 0);
+    assert_eq!(aliasable.0, 1);
+}
+
+#[test]
+fn test_drop() {
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    drop(AliasableThinBox::new(Counted(&drops)));
+    assert_eq!(drops.get(), 1);
+    drop(AliasableThinBox::<[Counted<'_>]>::from(vec![
+        Counted(&drops),
+        Counted(&drops),
+    ]));
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_zst() {
+    assert_eq!(*AliasableThinBox::new(()), ());
+    assert_eq!(AliasableThinBox::<[()]>::from(vec![(); 3]).len(), 3);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(format!("{:?}", AliasableThinBox::new(10)), "10");
+    assert_eq!(format!("{}", AliasableThinBox::new(10)), "10");
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(*<AliasableThinBox<i32>>::default(), 0);
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(AliasableThinBox::new(5), AliasableThinBox::new(7));
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(hash_of(AliasableThinBox::new(5)), hash_of(5));
+}
+
+#[cfg(feature = "unsize")]
+#[test]
+fn test_unsize() {
+    use core::fmt::Debug;
+    use unsize::{CoerceUnsize, Coercion};
+
+    let aliasable = AliasableThinBox::new([1u8, 2]);
+    let slice: AliasableThinBox<[u8]> = aliasable.unsize(Coercion::to_slice());
+    assert_eq!(*slice, [1, 2]);
+
+    let aliasable = AliasableThinBox::new(String::from("hello"));
+    let dyn_box: AliasableThinBox<dyn Debug> = aliasable.unsize(Coercion::to_debug());
+    assert_eq!(mem::size_of_val(&dyn_box), mem::size_of::<usize>());
+    assert_eq!(format!("{:?}", dyn_box), "\"hello\"");
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 mod common;
 
 use aliasable::prelude::{AliasableVec, UniqueVec};
@@ -21,6 +23,118 @@ fn test_new_pin() {
     assert_eq!(&*unique, &[10]);
 }
 
+#[test]
+fn test_const_new() {
+    static EMPTY: AliasableVec<u8> = AliasableVec::new();
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.capacity(), 0);
+
+    let v = AliasableVec::<u32>::new();
+    assert_eq!(v.as_ptr(), core::ptr::NonNull::<u32>::dangling().as_ptr());
+    assert_eq!(AliasableVec::<()>::new().capacity(), usize::MAX);
+    let mut unique = AliasableVec::into_unique(v);
+    unique.extend_from_slice(&[1, 2]);
+    assert_eq!(AliasableVec::from_unique(unique), [1, 2]);
+    drop(AliasableVec::<String>::new());
+}
+
+#[test]
+fn test_push_pop() {
+    let mut v = AliasableVec::new();
+    for i in 0..100 {
+        v.push(i.to_string());
+    }
+    assert_eq!(v.len(), 100);
+    assert!(v.capacity() >= 100);
+    assert_eq!(v.pop().as_deref(), Some("99"));
+    v.truncate(2);
+    assert_eq!(v, ["0", "1"]);
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(v.pop(), None);
+
+    let unique = AliasableVec::into_unique(v);
+    assert!(unique.capacity() >= 100);
+}
+
+#[test]
+fn test_with_capacity() {
+    let mut v = AliasableVec::with_capacity(10);
+    assert_eq!(v.capacity(), 10);
+    let ptr = v.as_ptr();
+    for i in 0..10 {
+        v.push(i);
+    }
+    assert_eq!(v.as_ptr(), ptr);
+    v.reserve(1);
+    assert!(v.capacity() >= 11);
+
+    let empty = AliasableVec::<u8>::with_capacity(0);
+    assert_eq!(empty.capacity(), 0);
+    assert_eq!(empty.as_ptr(), core::ptr::NonNull::dangling().as_ptr());
+}
+
+#[test]
+fn test_zst() {
+    use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Zst;
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+        }
+    }
+
+    let mut v = AliasableVec::new();
+    assert_eq!(v.capacity(), usize::MAX);
+    for _ in 0..10 {
+        v.push(Zst);
+    }
+    assert_eq!(v.len(), 10);
+    assert_eq!(v.capacity(), usize::MAX);
+    assert_eq!(v.as_ptr(), core::ptr::NonNull::dangling().as_ptr());
+    drop(v.pop());
+    assert_eq!(DROPS.load(SeqCst), 1);
+    v.truncate(5);
+    assert_eq!(DROPS.load(SeqCst), 5);
+
+    let unique = AliasableVec::into_unique(v);
+    assert_eq!(unique.len(), 5);
+    let v = AliasableVec::from_unique(unique);
+    assert_eq!(v.capacity(), usize::MAX);
+    drop(v);
+    assert_eq!(DROPS.load(SeqCst), 10);
+
+    let v = AliasableVec::from_unique(vec![(); 3]);
+    assert_eq!(v.len(), 3);
+    assert_eq!(AliasableVec::<()>::with_capacity(5).capacity(), usize::MAX);
+}
+
+#[test]
+fn test_drop_panic() {
+    use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct PanicOnDrop(bool);
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            let _ = DROPS.fetch_add(1, SeqCst);
+            assert!(!self.0, "drop panic");
+        }
+    }
+
+    let mut v = AliasableVec::new();
+    v.push(PanicOnDrop(false));
+    v.push(PanicOnDrop(true));
+    v.push(PanicOnDrop(false));
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(v))).is_err());
+    assert_eq!(DROPS.load(SeqCst), 3);
+}
+
 #[test]
 fn test_refs() {
     let mut aliasable = AliasableVec::from_unique(vec![10]);
@@ -80,3 +194,131 @@ fn test_hash() {
         hash_of([1, 2, 3])
     );
 }
+
+#[test]
+fn test_cmp_foreign() {
+    let aliasable = AliasableVec::from_unique(vec![1, 2, 3]);
+    assert_eq!(aliasable, [1, 2, 3]);
+    assert_eq!(aliasable, &[1, 2, 3]);
+    assert_eq!(aliasable, [1, 2, 3][..]);
+    assert_eq!(aliasable, &[1, 2, 3][..]);
+    assert_eq!(aliasable, vec![1, 2, 3]);
+    assert_eq!([1, 2, 3], aliasable);
+    assert_eq!(&[1, 2, 3][..], aliasable);
+    assert_eq!(vec![1, 2, 3], aliasable);
+    assert_ne!(aliasable, [1, 2]);
+    assert!(aliasable < [1, 2, 4]);
+    assert!(vec![1, 2] < aliasable);
+    assert!(aliasable > [1, 2][..]);
+}
+
+#[test]
+fn test_borrow() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    let _ = map.insert(AliasableVec::from_unique(vec![1, 2]), "a");
+    assert_eq!(map.get(&[1, 2][..]), Some(&"a"));
+    assert_eq!(map.get(&[1][..]), None);
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(AliasableVec::from(&[1, 2][..]), [1, 2]);
+    assert_eq!(AliasableVec::from(&mut [1, 2][..]), [1, 2]);
+    assert_eq!(AliasableVec::from([1, 2]), [1, 2]);
+    assert_eq!(AliasableVec::from("ab"), *b"ab");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_write() {
+    use std::io::Write;
+    let mut aliasable = AliasableVec::from_unique(vec![b'a']);
+    aliasable.write_all(b"bc").unwrap();
+    assert_eq!(aliasable.write(b"de").unwrap(), 2);
+    aliasable.flush().unwrap();
+    assert_eq!(&*aliasable, b"abcde");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_read() {
+    use std::io::{BufRead, Cursor, Read};
+    let aliasable = AliasableVec::from_unique(b"hello\nworld".to_vec());
+    let mut cursor = Cursor::new(aliasable);
+    let mut line = String::new();
+    assert_eq!(cursor.read_line(&mut line).unwrap(), 6);
+    assert_eq!(line, "hello\n");
+    let mut rest = Vec::new();
+    assert_eq!(cursor.read_to_end(&mut rest).unwrap(), 5);
+    assert_eq!(rest, b"world");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_buf_mut() {
+    use bytes::BufMut;
+
+    let mut aliasable = AliasableVec::from_unique(Vec::new());
+    aliasable.put_u16(0x0102);
+    aliasable.put_slice(b"abc");
+    for _ in 0..100 {
+        aliasable.put_u8(0);
+    }
+    assert_eq!(&aliasable[..5], b"\x01\x02abc");
+    assert_eq!(aliasable.len(), 105);
+    assert!(aliasable.remaining_mut() > 0);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_into_bytes() {
+    let aliasable = AliasableVec::from_unique(vec![1u8, 2, 3]);
+    let ptr = aliasable.as_ptr();
+    let bytes = bytes::Bytes::from(aliasable);
+    assert_eq!(bytes.as_ptr(), ptr);
+    assert_eq!(&bytes[..], [1, 2, 3]);
+}
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn test_futures_write() {
+    use core::task::{Context, Poll, Waker};
+    use futures_io::AsyncWrite;
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut aliasable = AliasableVec::from_unique(Vec::new());
+    assert!(matches!(
+        Pin::new(&mut aliasable).poll_write(&mut cx, b"abc"),
+        Poll::Ready(Ok(3))
+    ));
+    assert!(matches!(
+        Pin::new(&mut aliasable).poll_flush(&mut cx),
+        Poll::Ready(Ok(()))
+    ));
+    assert!(matches!(
+        Pin::new(&mut aliasable).poll_close(&mut cx),
+        Poll::Ready(Ok(()))
+    ));
+    assert_eq!(&*aliasable, b"abc");
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_tokio_write() {
+    use core::task::{Context, Poll, Waker};
+    use tokio::io::AsyncWrite;
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut aliasable = AliasableVec::from_unique(Vec::new());
+    assert!(matches!(
+        Pin::new(&mut aliasable).poll_write(&mut cx, b"abc"),
+        Poll::Ready(Ok(3))
+    ));
+    assert!(matches!(
+        Pin::new(&mut aliasable).poll_shutdown(&mut cx),
+        Poll::Ready(Ok(()))
+    ));
+    assert_eq!(&*aliasable, b"abc");
+}
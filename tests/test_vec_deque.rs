@@ -0,0 +1,161 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aliasable::prelude::{AliasableVecDeque, UniqueVecDeque};
+use std::cell::Cell;
+
+use self::common::{check_ordering, hash_of};
+
+fn deque<T>(elems: impl IntoIterator<Item = T>) -> AliasableVecDeque<T> {
+    AliasableVecDeque::from_unique(elems.into_iter().collect())
+}
+
+#[test]
+fn test_new() {
+    let aliasable = deque(vec![10, 11]);
+    assert_eq!(aliasable.as_slices(), (&[10, 11][..], &[][..]));
+    let unique = AliasableVecDeque::into_unique(aliasable);
+    assert_eq!(unique, [10, 11]);
+}
+
+#[test]
+fn test_push_pop() {
+    let mut aliasable = AliasableVecDeque::default();
+    assert_eq!(aliasable.pop_front(), None);
+    assert_eq!(aliasable.pop_back(), None);
+    aliasable.push_back(2);
+    aliasable.push_back(3);
+    aliasable.push_front(1);
+    aliasable.push_front(0);
+    assert_eq!(aliasable.len(), 4);
+    assert_eq!(aliasable.front(), Some(&0));
+    assert_eq!(aliasable.back(), Some(&3));
+    assert_eq!(aliasable.get(2), Some(&2));
+    assert_eq!(aliasable.get(4), None);
+    *aliasable.get_mut(1).unwrap() = 10;
+    assert_eq!(aliasable.pop_front(), Some(0));
+    assert_eq!(aliasable.pop_back(), Some(3));
+    assert_eq!(aliasable.pop_front(), Some(10));
+    assert_eq!(aliasable.pop_front(), Some(2));
+    assert!(aliasable.is_empty());
+    assert_eq!(aliasable.back(), None);
+}
+
+#[test]
+fn test_stable_addresses() {
+    let mut aliasable = AliasableVecDeque::from_unique(UniqueVecDeque::with_capacity(4));
+    let capacity = aliasable.capacity();
+    aliasable.push_back(1);
+    let ptr: *const i32 = aliasable.front().unwrap();
+    for i in 2..=capacity as i32 {
+        aliasable.push_front(i);
+    }
+    assert_eq!(aliasable.capacity(), capacity);
+    assert_eq!(aliasable.back().unwrap() as *const i32, ptr);
+    // SAFETY: The element has not been moved or dropped.
+    assert_eq!(unsafe { *ptr }, 1);
+}
+
+#[test]
+fn test_wrapping() {
+    let mut aliasable = AliasableVecDeque::from_unique(UniqueVecDeque::with_capacity(4));
+    let capacity = aliasable.capacity();
+    for i in 0..capacity {
+        aliasable.push_back(i);
+    }
+    aliasable.pop_front();
+    aliasable.pop_front();
+    aliasable.push_back(capacity);
+    let (front, back) = aliasable.as_slices();
+    assert_eq!(back, &[capacity]);
+    assert_eq!(front.len(), capacity - 2);
+    let expected: Vec<_> = (2..=capacity).collect();
+    assert_eq!(aliasable.make_contiguous(), &expected[..]);
+    assert_eq!(aliasable.as_slices().1, &[]);
+    assert_eq!(AliasableVecDeque::into_unique(aliasable), expected);
+}
+
+#[test]
+fn test_grow() {
+    let mut aliasable = AliasableVecDeque::default();
+    for i in 0..100 {
+        if i % 2 == 0 {
+            aliasable.push_back(i);
+        } else {
+            aliasable.push_front(i);
+        }
+    }
+    let unique = AliasableVecDeque::into_unique(aliasable);
+    let mut expected: UniqueVecDeque<_> = UniqueVecDeque::new();
+    for i in 0..100 {
+        if i % 2 == 0 {
+            expected.push_back(i);
+        } else {
+            expected.push_front(i);
+        }
+    }
+    assert_eq!(unique, expected);
+}
+
+#[test]
+fn test_drop() {
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut aliasable = AliasableVecDeque::default();
+    for _ in 0..10 {
+        aliasable.push_front(Counted(&drops));
+    }
+    drop(aliasable.pop_back());
+    assert_eq!(drops.get(), 1);
+    drop(aliasable);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn test_zst() {
+    let mut aliasable = AliasableVecDeque::default();
+    for _ in 0..10 {
+        aliasable.push_front(());
+        aliasable.push_back(());
+    }
+    assert_eq!(aliasable.len(), 20);
+    assert_eq!(aliasable.pop_front(), Some(()));
+    assert_eq!(AliasableVecDeque::into_unique(aliasable).len(), 19);
+}
+
+#[test]
+fn test_debug() {
+    let mut aliasable = deque(vec![1]);
+    aliasable.push_front(0);
+    assert_eq!(format!("{:?}", aliasable), "[0, 1]");
+}
+
+#[test]
+#[allow(clippy::redundant_clone)]
+fn test_clone() {
+    let mut aliasable = deque(vec![2, 3]);
+    aliasable.push_front(1);
+    assert_eq!(aliasable.clone(), aliasable);
+}
+
+#[test]
+fn test_cmp() {
+    check_ordering(deque(vec![1, 2, 3]), deque(vec![1, 2, 4]));
+}
+
+#[test]
+fn test_hash() {
+    let mut aliasable = deque(vec![2, 3]);
+    aliasable.push_front(1);
+    assert_eq!(
+        hash_of(aliasable),
+        hash_of(UniqueVecDeque::from(vec![1, 2, 3]))
+    );
+}
@@ -0,0 +1,70 @@
+#![cfg(all(feature = "alloc", feature = "yoke"))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec};
+use core::mem;
+use yoke::{Yoke, Yokeable};
+
+struct Fields<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+// SAFETY: `Fields` is covariant in `'a`, and `Output` is `Self` with `'static`
+// replaced by `'a`.
+unsafe impl<'a> Yokeable<'a> for Fields<'static> {
+    type Output = Fields<'a>;
+
+    fn transform(&'a self) -> &'a Fields<'a> {
+        self
+    }
+
+    fn transform_owned(self) -> Fields<'a> {
+        self
+    }
+
+    unsafe fn make(from: Fields<'a>) -> Self {
+        // SAFETY: The caller guarantees `from` is valid for as long as the
+        // returned value is used.
+        unsafe { mem::transmute::<Fields<'a>, Self>(from) }
+    }
+
+    fn transform_mut<F>(&'a mut self, f: F)
+    where
+        F: 'static + for<'b> FnOnce(&'b mut Fields<'a>),
+    {
+        // SAFETY: `f` can't stash the reference or its fields anywhere, as it
+        // is `'static` and generic over the lifetime of the reference.
+        unsafe { f(mem::transmute::<&mut Self, &mut Fields<'a>>(self)) }
+    }
+}
+
+fn parse(s: &str) -> Fields<'_> {
+    let (key, value) = s.split_once('=').unwrap();
+    Fields { key, value }
+}
+
+#[test]
+fn test_string_cart() {
+    let cart = AliasableString::from_unique(String::from("name=aliasable"));
+    let ptr = cart.as_ptr();
+    let yoke: Yoke<Fields<'static>, _> = Yoke::attach_to_cart(cart, |s: &str| parse(s));
+    let moved = Box::new(yoke);
+    assert_eq!(moved.get().key, "name");
+    assert_eq!(moved.get().value, "aliasable");
+    assert_eq!(moved.get().key.as_ptr(), ptr);
+    let cart = (*moved).into_backing_cart();
+    assert_eq!(cart, "name=aliasable");
+}
+
+#[test]
+fn test_bytes_cart() {
+    let cart = AliasableVec::from_unique(b"a=b".to_vec());
+    let yoke: Yoke<Fields<'static>, _> = Yoke::attach_to_cart(cart, |bytes: &[u8]| {
+        parse(core::str::from_utf8(bytes).unwrap())
+    });
+    assert_eq!(yoke.get().value, "b");
+
+    let cart = AliasableBox::from_unique(Box::<str>::from("c=d"));
+    let yoke: Yoke<Fields<'static>, _> = Yoke::attach_to_cart(cart, |s: &str| parse(s));
+    assert_eq!(yoke.get().key, "c");
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "alloc", feature = "zeroize"))]
+
+use aliasable::prelude::{AliasableBox, AliasableString, AliasableVec, ZeroizeOnDrop};
+use std::cell::Cell;
+use zeroize::Zeroize;
+
+#[test]
+fn test_box() {
+    let mut aliasable = AliasableBox::from_unique(Box::new([1u8, 2, 3]));
+    aliasable.zeroize();
+    assert_eq!(*aliasable, [0, 0, 0]);
+}
+
+#[test]
+fn test_vec_capacity() {
+    let mut unique = Vec::with_capacity(8);
+    unique.extend_from_slice(&[1u8; 8]);
+    unique.truncate(2);
+    let mut aliasable = AliasableVec::from_unique(unique);
+    let ptr = aliasable.as_ptr();
+    aliasable.zeroize();
+    assert!(aliasable.is_empty());
+    assert_eq!(aliasable.as_ptr(), ptr);
+    assert_eq!(aliasable.capacity(), 8);
+    // SAFETY: The whole capacity has been initialized by zeroizing it.
+    let wiped = unsafe { std::slice::from_raw_parts(ptr, 8) };
+    assert_eq!(wiped, [0; 8]);
+}
+
+#[test]
+fn test_string() {
+    let mut aliasable = AliasableString::from_unique(String::from("secret"));
+    aliasable.zeroize();
+    assert!(aliasable.is_empty());
+    // Take a pointer covering the whole buffer, not just the empty string.
+    let bytes = aliasable.into_bytes();
+    // SAFETY: The whole capacity has been initialized by zeroizing it.
+    let wiped = unsafe { std::slice::from_raw_parts(bytes.as_ptr(), 6) };
+    assert_eq!(wiped, [0; 6]);
+}
+
+#[test]
+fn test_zeroize_on_drop() {
+    struct Flag<'a>(&'a Cell<bool>);
+    impl Zeroize for Flag<'_> {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let zeroized = Cell::new(false);
+    let wrapper = ZeroizeOnDrop::new(Flag(&zeroized));
+    assert!(!zeroized.get());
+    drop(wrapper);
+    assert!(zeroized.get());
+}
+
+#[test]
+fn test_zeroize_on_drop_deref() {
+    let mut wrapper = ZeroizeOnDrop::from(AliasableVec::from_unique(vec![1u8, 2]));
+    let ptr = wrapper.as_ptr();
+    wrapper[0] = 10;
+    let moved = wrapper;
+    assert_eq!(moved.as_ptr(), ptr);
+    assert_eq!(&**moved, [10, 2]);
+    assert_eq!(moved.clone(), moved);
+    assert_eq!(format!("{:?}", moved), "ZeroizeOnDrop([10, 2])");
+}